@@ -1,10 +1,10 @@
-/// 基本使用示例
-/// 
-/// 本示例展示 KOOK SDK 的基本使用方法，包括：
-/// - 创建客户端
-/// - 获取用户信息
-/// - 发送消息
-/// - 获取频道和服务器列表
+//! 基本使用示例
+//! 
+//! 本示例展示 KOOK SDK 的基本使用方法，包括：
+//! - 创建客户端
+//! - 获取用户信息
+//! - 发送消息
+//! - 获取频道和服务器列表
 
 use kook_sdk::{KookClient, PageParams};
 
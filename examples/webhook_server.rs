@@ -1,14 +1,14 @@
-/// KOOK SDK Webhook 服务器示例
-/// 
-/// 本示例展示如何使用 KOOK SDK 创建 Webhook 服务器，实现：
-/// - 接收 KOOK 平台推送的事件
-/// - 验证 Webhook 签名
-/// - 处理挑战验证
-/// - 自定义事件处理逻辑
+//! KOOK SDK Webhook 服务器示例
+//! 
+//! 本示例展示如何使用 KOOK SDK 创建 Webhook 服务器，实现：
+//! - 接收 KOOK 平台推送的事件
+//! - 验证 Webhook 签名
+//! - 处理挑战验证
+//! - 自定义事件处理逻辑
 
 use kook_sdk::{
     WebhookConfig, WebhookHandler, WebhookEvent, WebhookChallenge, 
-    KookError, EventData, start_webhook_server
+    Event, KookError, MessageType, SystemEventKind, start_webhook_server
 };
 use std::env;
 
@@ -25,68 +25,53 @@ impl MyWebhookHandler {
 }
 
 impl WebhookHandler for MyWebhookHandler {
-    fn handle_event(
-        &self,
-        event: WebhookEvent,
-    ) -> impl std::future::Future<Output = Result<(), KookError>> + Send {
-        async move {
-            println!("收到 Webhook 事件:");
-            println!("  序列号: {}", event.sn);
-            
-            // 解析事件数据
-            let event_data = &event.d;
-            match event_data.r#type {
-                1 => {
-                    // 文本消息事件
-                    println!("  类型: 文本消息");
-                    println!("  发送者: {}", event_data.author_id);
-                    println!("  频道: {}", event_data.target_id);
-                    println!("  内容: {}", event_data.content);
-                    println!("  消息ID: {}", event_data.msg_id);
-                    println!("  时间戳: {}", event_data.msg_timestamp);
-                    
-                    // 这里可以添加自定义的消息处理逻辑
-                    // 例如：关键词回复、命令处理、数据库存储等
-                    if event_data.content.contains("帮助") {
-                        println!("  检测到帮助请求，可以在这里实现自动回复");
-                    }
+    async fn handle_event(&self, event: WebhookEvent) -> Result<(), KookError> {
+        println!("收到 Webhook 事件:");
+        println!("  序列号: {}", event.sn);
+        
+        // 解析为强类型事件
+        match Event::from(event.d) {
+            Event::Message(message) => {
+                println!("  类型: {:?} 消息", message.message_type);
+                println!("  发送者: {} ({})", message.author_name(), message.author_id);
+                println!("  频道: {}", message.extra.channel_name.as_deref().unwrap_or(&message.target_id));
+                println!("  内容: {}", message.content);
+                println!("  消息ID: {}", message.msg_id);
+                println!("  时间戳: {}", message.msg_timestamp);
+
+                // 这里可以添加自定义的消息处理逻辑
+                // 例如：关键词回复、命令处理、数据库存储等
+                if message.message_type == MessageType::Text && message.content.contains("帮助") {
+                    println!("  检测到帮助请求，可以在这里实现自动回复");
                 }
-                2 => {
-                    println!("  类型: 图片消息");
-                    println!("  发送者: {}", event_data.author_id);
-                    println!("  频道: {}", event_data.target_id);
-                }
-                8 => {
-                    println!("  类型: 音频消息");
-                    println!("  发送者: {}", event_data.author_id);
-                    println!("  频道: {}", event_data.target_id);
+            }
+            Event::System(system) => match system.kind {
+                SystemEventKind::JoinedGuild(joined) => {
+                    println!("  类型: 新成员加入服务器 {}", system.target_id);
+                    println!("  用户: {}", joined.user_id);
                 }
-                9 => {
-                    println!("  类型: 视频消息");
-                    println!("  发送者: {}", event_data.author_id);
-                    println!("  频道: {}", event_data.target_id);
+                SystemEventKind::ExitedGuild(exited) => {
+                    println!("  类型: 成员退出服务器 {}", system.target_id);
+                    println!("  用户: {}", exited.user_id);
                 }
-                255 => {
-                    println!("  类型: 系统消息");
-                    println!("  内容: {}", event_data.content);
+                SystemEventKind::MessageBtnClick(click) => {
+                    println!("  类型: 卡片按钮点击");
+                    println!("  用户: {} 按钮值: {}", click.user_id, click.value);
                 }
-                _ => {
-                    println!("  类型: 未知类型 ({})", event_data.r#type);
-                    println!("  发送者: {}", event_data.author_id);
-                    println!("  频道: {}", event_data.target_id);
+                other => {
+                    println!("  类型: 系统事件 {:?}", other);
                 }
+            },
+            Event::Raw(data) => {
+                println!("  类型: 未知类型 ({})", data.r#type);
+                println!("  额外数据: {}", data.extra);
             }
-            
-            // 处理额外数据
-            if !event_data.extra.is_null() {
-                println!("  额外数据: {}", event_data.extra);
-            }
-            
-            println!("  处理完成");
-            println!("---");
-            
-            Ok(())
         }
+        
+        println!("  处理完成");
+        println!("---");
+        
+        Ok(())
     }
 
     fn handle_challenge(
@@ -1,12 +1,12 @@
-/// KOOK SDK WebSocket 客户端示例
-/// 
-/// 本示例展示如何使用 KOOK SDK 建立 WebSocket 连接，实现：
-/// - 实时接收消息和事件
-/// - 自动心跳保持连接
-/// - 断线自动重连
-/// - 自定义事件处理
+//! KOOK SDK WebSocket 客户端示例
+//! 
+//! 本示例展示如何使用 KOOK SDK 建立 WebSocket 连接，实现：
+//! - 实时接收消息和事件
+//! - 自动心跳保持连接
+//! - 断线自动重连
+//! - 自定义事件处理
 
-use kook_sdk::{KookClient, WebSocketClient, EventHandler, EventData, HelloData};
+use kook_sdk::{KookClient, KookWebSocketClient, EventHandler, EventData, HelloData, MessageType};
 use std::sync::Arc;
 
 /// 自定义事件处理器
@@ -42,7 +42,7 @@ impl EventHandler for MyEventHandler {
                         if let Err(e) = client.send_message(
                             &event.target_id,
                             "pong",
-                            Some(1), // 文本消息类型
+                            Some(MessageType::Text), // 文本消息类型
                             Some(&event.msg_id), // 引用原消息
                         ).await {
                             eprintln!("  回复消息失败: {}", e);
@@ -89,16 +89,22 @@ impl EventHandler for MyEventHandler {
         }
     }
 
-    fn on_hello(&self, hello: HelloData) -> impl std::future::Future<Output = ()> + Send {
-        async move {
-            println!("WebSocket 连接建立成功!");
-            println!("  状态码: {}", hello.code);
-            if let Some(session_id) = &hello.session_id {
-                println!("  会话ID: {}", session_id);
-            }
-            println!("  开始监听事件...");
-            println!("---");
+    async fn on_hello(&self, hello: HelloData) {
+        println!("WebSocket 连接建立成功!");
+        println!("  状态码: {}", hello.code);
+        if let Some(session_id) = &hello.session_id {
+            println!("  会话ID: {}", session_id);
         }
+        println!("  开始监听事件...");
+        println!("---");
+    }
+
+    async fn on_reconnect(&self, code: i32, message: String) {
+        println!("服务器要求重连: {} - {}", code, message);
+    }
+
+    async fn on_resume_ack(&self, session_id: String) {
+        println!("会话恢复成功: {}", session_id);
     }
 }
 
@@ -138,7 +144,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // 4. 创建并启动 WebSocket 客户端
     println!("\n4. 启动 WebSocket 连接...");
-    let ws_client = WebSocketClient::new(client);
+    let mut ws_client = KookWebSocketClient::new(client, false);
     
     // 连接并开始监听（这将阻塞直到连接关闭）
     if let Err(e) = ws_client.connect(event_handler).await {
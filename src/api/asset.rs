@@ -0,0 +1,118 @@
+//! 媒体文件上传接口
+use reqwest::multipart::{Form, Part};
+use reqwest::Body;
+use std::path::{Path, PathBuf};
+use tokio::io::AsyncRead;
+use tokio_util::io::ReaderStream;
+use crate::client::KookClient;
+use crate::models::{Asset, KookError};
+
+/// 待上传文件的数据来源
+enum AssetSource {
+    /// 本地文件路径
+    Path(PathBuf),
+    /// 内存中的数据
+    Bytes(Vec<u8>),
+    /// 异步读取的数据流，以及可选的数据长度
+    Reader(Box<dyn AsyncRead + Send + Sync + Unpin>, Option<u64>),
+}
+
+/// 待上传的媒体文件 (图片、视频、音频、文件)
+pub struct AssetUpload {
+    source: AssetSource,
+    file_name: String,
+    mime_type: Option<String>,
+}
+
+impl AssetUpload {
+    /// 从本地文件上传，文件名取自路径
+    pub fn from_path(path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+        let file_name = path.file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "file".to_string());
+        Self {
+            source: AssetSource::Path(path.to_path_buf()),
+            file_name,
+            mime_type: None,
+        }
+    }
+
+    /// 从内存数据上传
+    pub fn from_bytes(bytes: impl Into<Vec<u8>>, file_name: &str) -> Self {
+        Self {
+            source: AssetSource::Bytes(bytes.into()),
+            file_name: file_name.to_string(),
+            mime_type: None,
+        }
+    }
+
+    /// 从异步数据流上传，数据会边读取边发送
+    pub fn from_reader<R>(reader: R, file_name: &str) -> Self
+    where
+        R: AsyncRead + Send + Sync + Unpin + 'static,
+    {
+        Self {
+            source: AssetSource::Reader(Box::new(reader), None),
+            file_name: file_name.to_string(),
+            mime_type: None,
+        }
+    }
+
+    /// 设置文件名
+    pub fn file_name(mut self, file_name: &str) -> Self {
+        self.file_name = file_name.to_string();
+        self
+    }
+
+    /// 设置 MIME 类型，未设置时根据文件扩展名推断
+    pub fn mime_type(mut self, mime_type: &str) -> Self {
+        self.mime_type = Some(mime_type.to_string());
+        self
+    }
+
+    /// 设置数据流的长度 (仅对 `from_reader` 有效)，便于服务端校验文件大小
+    pub fn content_length(mut self, length: u64) -> Self {
+        if let AssetSource::Reader(_, len) = &mut self.source {
+            *len = Some(length);
+        }
+        self
+    }
+
+    /// 构建 multipart 表单中的文件字段
+    pub(crate) async fn into_part(self) -> Result<Part, KookError> {
+        let mime_type = self.mime_type.unwrap_or_else(|| {
+            mime_guess::from_path(&self.file_name)
+                .first_or_octet_stream()
+                .to_string()
+        });
+
+        let part = match self.source {
+            AssetSource::Path(path) => {
+                let file = tokio::fs::File::open(&path).await
+                    .map_err(|e| KookError::Params(format!("无法打开文件 {}: {}", path.display(), e)))?;
+                let length = file.metadata().await
+                    .map_err(|e| KookError::Params(format!("无法读取文件信息 {}: {}", path.display(), e)))?
+                    .len();
+                Part::stream_with_length(Body::wrap_stream(ReaderStream::new(file)), length)
+            }
+            AssetSource::Bytes(bytes) => Part::bytes(bytes),
+            AssetSource::Reader(reader, Some(length)) => {
+                Part::stream_with_length(Body::wrap_stream(ReaderStream::new(reader)), length)
+            }
+            AssetSource::Reader(reader, None) => Part::stream(Body::wrap_stream(ReaderStream::new(reader))),
+        };
+
+        part.file_name(self.file_name)
+            .mime_str(&mime_type)
+            .map_err(|e| KookError::Params(format!("无效的 MIME 类型 {}: {}", mime_type, e)))
+    }
+}
+
+impl KookClient {
+    /// 上传媒体文件，返回可用于发送图片、视频、文件、音频消息的地址
+    pub async fn upload_asset(&self, upload: AssetUpload) -> Result<Asset, KookError> {
+        let form = Form::new().part("file", upload.into_part().await?);
+        self.multipart_request("/v3/asset/create", form).await
+    }
+}
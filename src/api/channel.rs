@@ -0,0 +1,124 @@
+//! 频道及频道权限相关接口
+use reqwest::Method;
+use serde_json::json;
+use crate::client::KookClient;
+use crate::models::*;
+use crate::permission::Permission;
+
+impl KookClient {
+    /// 获取频道详情
+    pub async fn get_channel(&self, channel_id: &str) -> Result<Channel, KookError> {
+        let query = [("target_id", channel_id)];
+        self.api_request(Method::GET, "/v3/channel/view", Some(&query), None).await
+    }
+
+    /// 创建频道
+    pub async fn create_channel(&self, channel: &CreateChannel) -> Result<Channel, KookError> {
+        let body = serde_json::to_value(channel)?;
+        self.api_request(Method::POST, "/v3/channel/create", None, Some(&body)).await
+    }
+
+    /// 编辑频道
+    pub async fn update_channel(&self, channel_id: &str, update: &UpdateChannel) -> Result<Channel, KookError> {
+        let mut body = serde_json::to_value(update)?;
+        body["channel_id"] = channel_id.into();
+        self.api_request(Method::POST, "/v3/channel/update", None, Some(&body)).await
+    }
+
+    /// 删除频道
+    pub async fn delete_channel(&self, channel_id: &str) -> Result<(), KookError> {
+        let body = json!({ "channel_id": channel_id });
+        self.api_action("/v3/channel/delete", &body).await
+    }
+
+    /// 获取语音频道中的用户列表
+    pub async fn get_channel_users(&self, channel_id: &str) -> Result<Vec<User>, KookError> {
+        let query = [("channel_id", channel_id)];
+        self.api_request(Method::GET, "/v3/channel/user-list", Some(&query), None).await
+    }
+
+    /// 将用户移动到指定的语音频道
+    pub async fn move_channel_users(&self, target_id: &str, user_ids: &[&str]) -> Result<(), KookError> {
+        let body = json!({ "target_id": target_id, "user_ids": user_ids });
+        self.api_action("/v3/channel/move-user", &body).await
+    }
+
+    /// 获取频道的角色权限
+    pub async fn get_channel_roles(&self, channel_id: &str) -> Result<ChannelRoleIndex, KookError> {
+        let query = [("channel_id", channel_id)];
+        self.api_request(Method::GET, "/v3/channel-role/index", Some(&query), None).await
+    }
+
+    /// 为角色或用户创建频道权限
+    pub async fn create_channel_role(
+        &self,
+        channel_id: &str,
+        target: &ChannelRoleTarget,
+    ) -> Result<ChannelRole, KookError> {
+        let body = json!({
+            "channel_id": channel_id,
+            "type": target.type_param(),
+            "value": target.value_param(),
+        });
+        self.api_request(Method::POST, "/v3/channel-role/create", None, Some(&body)).await
+    }
+
+    /// 更新角色或用户的频道权限
+    pub async fn update_channel_role(
+        &self,
+        channel_id: &str,
+        target: &ChannelRoleTarget,
+        allow: Permission,
+        deny: Permission,
+    ) -> Result<ChannelRole, KookError> {
+        let body = json!({
+            "channel_id": channel_id,
+            "type": target.type_param(),
+            "value": target.value_param(),
+            "allow": allow.bits(),
+            "deny": deny.bits(),
+        });
+        self.api_request(Method::POST, "/v3/channel-role/update", None, Some(&body)).await
+    }
+
+    /// 删除角色或用户的频道权限
+    pub async fn delete_channel_role(&self, channel_id: &str, target: &ChannelRoleTarget) -> Result<(), KookError> {
+        let body = json!({
+            "channel_id": channel_id,
+            "type": target.type_param(),
+            "value": target.value_param(),
+        });
+        self.api_action("/v3/channel-role/delete", &body).await
+    }
+
+    /// 将频道权限与所属分组同步
+    pub async fn sync_channel_roles(&self, channel_id: &str) -> Result<ChannelRoleIndex, KookError> {
+        let body = json!({ "channel_id": channel_id });
+        self.api_request(Method::POST, "/v3/channel-role/sync", None, Some(&body)).await
+    }
+
+    /// 为角色或用户设置频道权限，权限记录不存在时先创建
+    ///
+    /// 组合调用 channel-role/index、create 和 update。
+    pub async fn grant_channel_role(
+        &self,
+        channel_id: &str,
+        target: &ChannelRoleTarget,
+        allow: Permission,
+        deny: Permission,
+    ) -> Result<ChannelRole, KookError> {
+        let index = self.get_channel_roles(channel_id).await?;
+        let exists = match target {
+            ChannelRoleTarget::Role(role_id) => {
+                index.permission_overwrites.iter().any(|o| o.role_id == *role_id)
+            }
+            ChannelRoleTarget::User(user_id) => {
+                index.permission_users.iter().any(|o| o.user.id == *user_id)
+            }
+        };
+        if !exists {
+            self.create_channel_role(channel_id, target).await?;
+        }
+        self.update_channel_role(channel_id, target, allow, deny).await
+    }
+}
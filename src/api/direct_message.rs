@@ -0,0 +1,126 @@
+//! 私聊会话和私信相关接口
+use futures_util::Stream;
+use reqwest::Method;
+use serde_json::json;
+use crate::client::{KookClient, PageParams, PaginateOptions};
+use crate::models::*;
+
+impl KookClient {
+    /// 获取私聊会话列表
+    pub async fn get_user_chats(&self, params: &PageParams) -> Result<PagedResponse<UserChat>, KookError> {
+        self.paged_request(Method::GET, "/v3/user-chat/list", params, None).await
+    }
+
+    /// 自动分页获取私聊会话
+    pub fn paginate_user_chats(
+        &self,
+        options: PaginateOptions,
+    ) -> impl Stream<Item = Result<UserChat, KookError>> + '_ {
+        self.paginate("/v3/user-chat/list", &[], options)
+    }
+
+    /// 获取私聊会话详情
+    pub async fn get_user_chat(&self, chat_code: &str) -> Result<UserChat, KookError> {
+        let query = [("chat_code", chat_code)];
+        self.api_request(Method::GET, "/v3/user-chat/view", Some(&query), None).await
+    }
+
+    /// 创建与指定用户的私聊会话，会话已存在时返回已有的会话
+    pub async fn create_user_chat(&self, target_id: &str) -> Result<UserChat, KookError> {
+        let body = json!({ "target_id": target_id });
+        self.api_request(Method::POST, "/v3/user-chat/create", None, Some(&body)).await
+    }
+
+    /// 删除私聊会话
+    pub async fn delete_user_chat(&self, chat_code: &str) -> Result<(), KookError> {
+        let body = json!({ "chat_code": chat_code });
+        self.api_action("/v3/user-chat/delete", &body).await
+    }
+
+    /// 获取私信聊天消息列表
+    pub async fn list_direct_messages(
+        &self,
+        target: &DirectMessageTarget,
+        filter: &MessageListQuery,
+    ) -> Result<Vec<DirectMessage>, KookError> {
+        let (key, value) = target.as_param();
+        let mut query_params = vec![(key, value.to_string())];
+        query_params.extend(filter.to_query());
+
+        let query: Vec<(&str, &str)> = query_params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        let list: DirectMessageList = self.api_request(Method::GET, "/v3/direct-message/list", Some(&query), None).await?;
+        Ok(list.items)
+    }
+
+    /// 获取私信消息详情
+    pub async fn get_direct_message(&self, chat_code: &str, msg_id: &str) -> Result<DirectMessage, KookError> {
+        let query = [("chat_code", chat_code), ("msg_id", msg_id)];
+        self.api_request(Method::GET, "/v3/direct-message/view", Some(&query), None).await
+    }
+
+    /// 发送私信
+    pub async fn create_direct_message(&self, message: &CreateDirectMessage) -> Result<MessageCreated, KookError> {
+        if message.target_id.is_none() && message.chat_code.is_none() {
+            return Err(KookError::Params("发送私信需要 target_id 或 chat_code".to_string()));
+        }
+        let body = serde_json::to_value(message)?;
+        self.api_request(Method::POST, "/v3/direct-message/create", None, Some(&body)).await
+    }
+
+    /// 给指定用户发送文字私信
+    pub async fn send_direct_message(&self, target_id: &str, content: &str) -> Result<MessageCreated, KookError> {
+        let target = DirectMessageTarget::User(target_id.to_string());
+        self.create_direct_message(&CreateDirectMessage::new(&target, content)).await
+    }
+
+    /// 编辑私信，目前只支持 KMarkdown 和卡片消息
+    pub async fn update_direct_message(&self, update: &UpdateMessage) -> Result<(), KookError> {
+        let body = serde_json::to_value(update)?;
+        self.api_action("/v3/direct-message/update", &body).await
+    }
+
+    /// 删除私信，只能删除自己发送的消息
+    pub async fn delete_direct_message(&self, msg_id: &str) -> Result<(), KookError> {
+        let body = json!({ "msg_id": msg_id });
+        self.api_action("/v3/direct-message/delete", &body).await
+    }
+
+    /// 获取回应了私信中某个表情的用户列表
+    pub async fn get_direct_reaction_users(
+        &self,
+        msg_id: &str,
+        emoji: &str,
+    ) -> Result<Vec<ReactionUser>, KookError> {
+        let query = [("msg_id", msg_id), ("emoji", emoji)];
+        self.api_request(Method::GET, "/v3/direct-message/reaction-list", Some(&query), None).await
+    }
+
+    /// 给私信添加回应
+    pub async fn add_direct_reaction(&self, msg_id: &str, emoji: &str) -> Result<(), KookError> {
+        let body = json!({ "msg_id": msg_id, "emoji": emoji });
+        self.api_action("/v3/direct-message/add-reaction", &body).await
+    }
+
+    /// 删除自己在私信上的回应
+    pub async fn delete_direct_reaction(&self, msg_id: &str, emoji: &str) -> Result<(), KookError> {
+        let body = json!({ "msg_id": msg_id, "emoji": emoji });
+        self.api_action("/v3/direct-message/delete-reaction", &body).await
+    }
+
+    /// 以私信回复一条私聊消息事件，并引用原消息
+    ///
+    /// 事件中带有私信会话 Code 时发往该会话，否则发给消息作者。
+    pub async fn reply_direct_message(&self, event: &EventData, content: &str) -> Result<MessageCreated, KookError> {
+        if !event.is_direct_message() {
+            return Err(KookError::Params(format!(
+                "事件 {} 不是私聊消息 (channel_type: {})", event.msg_id, event.channel_type
+            )));
+        }
+        let target = match event.chat_code() {
+            Some(chat_code) => DirectMessageTarget::Chat(chat_code.to_string()),
+            None => DirectMessageTarget::User(event.author_id.clone()),
+        };
+        let message = CreateDirectMessage::new(&target, content).quote(&event.msg_id);
+        self.create_direct_message(&message).await
+    }
+}
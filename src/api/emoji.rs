@@ -0,0 +1,54 @@
+//! 服务器表情相关接口
+use futures_util::Stream;
+use reqwest::multipart::Form;
+use reqwest::Method;
+use serde_json::json;
+use crate::api::AssetUpload;
+use crate::client::{KookClient, PageParams, PaginateOptions};
+use crate::models::*;
+
+impl KookClient {
+    /// 获取服务器表情列表
+    pub async fn get_guild_emojis(&self, guild_id: &str, params: &PageParams) -> Result<PagedResponse<GuildEmoji>, KookError> {
+        let query = [("guild_id", guild_id)];
+        self.paged_request(Method::GET, "/v3/guild-emoji/list", params, Some(&query)).await
+    }
+
+    /// 自动分页获取服务器表情
+    pub fn paginate_guild_emojis<'a>(
+        &'a self,
+        guild_id: &str,
+        options: PaginateOptions,
+    ) -> impl Stream<Item = Result<GuildEmoji, KookError>> + 'a {
+        self.paginate("/v3/guild-emoji/list", &[("guild_id", guild_id)], options)
+    }
+
+    /// 上传服务器表情，`name` 为空时使用随机名称
+    ///
+    /// 图片大小不能超过 256KB，名称为 2~32 个字符。
+    pub async fn create_guild_emoji(
+        &self,
+        guild_id: &str,
+        name: Option<&str>,
+        image: AssetUpload,
+    ) -> Result<GuildEmoji, KookError> {
+        let mut form = Form::new().text("guild_id", guild_id.to_string());
+        if let Some(name) = name {
+            form = form.text("name", name.to_string());
+        }
+        form = form.part("emoji", image.into_part().await?);
+        self.multipart_request("/v3/guild-emoji/create", form).await
+    }
+
+    /// 修改服务器表情名称
+    pub async fn update_guild_emoji(&self, id: &str, name: &str) -> Result<(), KookError> {
+        let body = json!({ "id": id, "name": name });
+        self.api_action("/v3/guild-emoji/update", &body).await
+    }
+
+    /// 删除服务器表情
+    pub async fn delete_guild_emoji(&self, id: &str) -> Result<(), KookError> {
+        let body = json!({ "id": id });
+        self.api_action("/v3/guild-emoji/delete", &body).await
+    }
+}
@@ -0,0 +1,64 @@
+//! 游戏和动态相关接口
+use reqwest::Method;
+use serde_json::json;
+use crate::client::{KookClient, PageParams};
+use crate::models::*;
+
+impl KookClient {
+    /// 获取游戏列表
+    pub async fn get_games(&self, list_type: GameListType, params: &PageParams) -> Result<PagedResponse<Game>, KookError> {
+        let list_type = (list_type as i32).to_string();
+        let query = [("type", list_type.as_str())];
+        self.paged_request(Method::GET, "/v3/game", params, Some(&query)).await
+    }
+
+    /// 添加游戏
+    pub async fn create_game(&self, name: &str, icon: Option<&str>) -> Result<Game, KookError> {
+        let mut body = json!({ "name": name });
+        if let Some(icon) = icon {
+            body["icon"] = icon.into();
+        }
+        self.api_request(Method::POST, "/v3/game/create", None, Some(&body)).await
+    }
+
+    /// 更新游戏的名称或图标
+    pub async fn update_game(&self, id: i64, name: Option<&str>, icon: Option<&str>) -> Result<Game, KookError> {
+        let mut body = json!({ "id": id });
+        if let Some(name) = name {
+            body["name"] = name.into();
+        }
+        if let Some(icon) = icon {
+            body["icon"] = icon.into();
+        }
+        self.api_request(Method::POST, "/v3/game/update", None, Some(&body)).await
+    }
+
+    /// 删除游戏
+    pub async fn delete_game(&self, id: i64) -> Result<(), KookError> {
+        let body = json!({ "id": id });
+        self.api_action("/v3/game/delete", &body).await
+    }
+
+    /// 设置机器人正在玩的游戏或正在听的音乐
+    pub async fn set_activity(&self, activity: &Activity) -> Result<(), KookError> {
+        let body = match activity {
+            Activity::Playing { game_id } => json!({
+                "data_type": ActivityType::Game as i32,
+                "id": game_id,
+            }),
+            Activity::Listening { software, singer, music_name } => json!({
+                "data_type": ActivityType::Music as i32,
+                "software": software.as_param(),
+                "singer": singer,
+                "music_name": music_name,
+            }),
+        };
+        self.api_action("/v3/game/activity", &body).await
+    }
+
+    /// 清除机器人正在玩的游戏或正在听的音乐
+    pub async fn delete_activity(&self, activity_type: ActivityType) -> Result<(), KookError> {
+        let body = json!({ "data_type": activity_type as i32 });
+        self.api_action("/v3/game/delete-activity", &body).await
+    }
+}
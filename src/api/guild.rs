@@ -0,0 +1,163 @@
+//! 服务器相关接口
+use futures_util::Stream;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use reqwest::Method;
+use serde_json::json;
+use crate::client::{KookClient, PageParams, PaginateOptions};
+use crate::models::*;
+
+impl KookClient {
+    /// 获取服务器详情
+    pub async fn get_guild(&self, guild_id: &str) -> Result<GuildView, KookError> {
+        let query = [("guild_id", guild_id)];
+        self.api_request(Method::GET, "/v3/guild/view", Some(&query), None).await
+    }
+
+    /// 获取服务器中的用户列表
+    pub async fn get_guild_users(
+        &self,
+        guild_id: &str,
+        filter: &GuildUserQuery,
+        params: &PageParams,
+    ) -> Result<GuildUserList, KookError> {
+        let mut query_params = vec![("guild_id", guild_id.to_string())];
+        query_params.extend(filter.to_query());
+        query_params.extend(params.to_query());
+
+        let query: Vec<(&str, &str)> = query_params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.api_request(Method::GET, "/v3/guild/user-list", Some(&query), None).await
+    }
+
+    /// 自动分页获取服务器中的用户
+    pub fn paginate_guild_users<'a>(
+        &'a self,
+        guild_id: &str,
+        filter: &GuildUserQuery,
+        options: PaginateOptions,
+    ) -> impl Stream<Item = Result<GuildMember, KookError>> + 'a {
+        let filter_query = filter.to_query();
+        let mut query = vec![("guild_id", guild_id)];
+        query.extend(filter_query.iter().map(|(k, v)| (*k, v.as_str())));
+        self.paginate("/v3/guild/user-list", &query, options)
+    }
+
+    /// 修改服务器中用户的昵称
+    ///
+    /// `user_id` 为空时修改当前用户的昵称，`nickname` 为空时重置为用户名。
+    pub async fn set_guild_nickname(
+        &self,
+        guild_id: &str,
+        nickname: Option<&str>,
+        user_id: Option<&str>,
+    ) -> Result<(), KookError> {
+        let mut body = json!({ "guild_id": guild_id });
+        if let Some(nickname) = nickname {
+            body["nickname"] = nickname.into();
+        }
+        if let Some(user_id) = user_id {
+            body["user_id"] = user_id.into();
+        }
+        self.api_action("/v3/guild/nickname", &body).await
+    }
+
+    /// 离开服务器
+    pub async fn leave_guild(&self, guild_id: &str) -> Result<(), KookError> {
+        let body = json!({ "guild_id": guild_id });
+        self.api_action("/v3/guild/leave", &body).await
+    }
+
+    /// 将用户踢出服务器
+    pub async fn kick_guild_user(&self, guild_id: &str, target_id: &str) -> Result<(), KookError> {
+        let body = json!({ "guild_id": guild_id, "target_id": target_id });
+        self.api_action("/v3/guild/kickout", &body).await
+    }
+
+    /// 获取服务器静音/闭麦列表
+    pub async fn get_guild_mutes(&self, guild_id: &str) -> Result<GuildMuteList, KookError> {
+        let query = [("guild_id", guild_id), ("return_type", "detail")];
+        self.api_request(Method::GET, "/v3/guild-mute/list", Some(&query), None).await
+    }
+
+    /// 添加服务器静音/闭麦
+    pub async fn create_guild_mute(
+        &self,
+        guild_id: &str,
+        user_id: &str,
+        mute_type: MuteType,
+    ) -> Result<(), KookError> {
+        let body = json!({ "guild_id": guild_id, "user_id": user_id, "type": mute_type as i32 });
+        self.api_action("/v3/guild-mute/create", &body).await
+    }
+
+    /// 删除服务器静音/闭麦
+    pub async fn delete_guild_mute(
+        &self,
+        guild_id: &str,
+        user_id: &str,
+        mute_type: MuteType,
+    ) -> Result<(), KookError> {
+        let body = json!({ "guild_id": guild_id, "user_id": user_id, "type": mute_type as i32 });
+        self.api_action("/v3/guild-mute/delete", &body).await
+    }
+
+    /// 获取服务器黑名单
+    pub async fn get_blacklist(&self, guild_id: &str, params: &PageParams) -> Result<PagedResponse<BlacklistEntry>, KookError> {
+        let query = [("guild_id", guild_id)];
+        self.paged_request(Method::GET, "/v3/blacklist/list", params, Some(&query)).await
+    }
+
+    /// 将用户加入服务器黑名单
+    ///
+    /// `del_msg_days` 为删除该用户最近几天的消息，最大为 7 天。
+    pub async fn create_blacklist(
+        &self,
+        guild_id: &str,
+        target_id: &str,
+        remark: Option<&str>,
+        del_msg_days: Option<u8>,
+    ) -> Result<(), KookError> {
+        let mut body = json!({ "guild_id": guild_id, "target_id": target_id });
+        if let Some(remark) = remark {
+            body["remark"] = remark.into();
+        }
+        if let Some(days) = del_msg_days {
+            if days > 7 {
+                return Err(KookError::Params(format!("del_msg_days 不能超过 7 天: {}", days)));
+            }
+            body["del_msg_days"] = days.into();
+        }
+        self.api_action("/v3/blacklist/create", &body).await
+    }
+
+    /// 将用户移出服务器黑名单
+    pub async fn delete_blacklist(&self, guild_id: &str, target_id: &str) -> Result<(), KookError> {
+        let body = json!({ "guild_id": guild_id, "target_id": target_id });
+        self.api_action("/v3/blacklist/delete", &body).await
+    }
+
+    /// 服务器徽章图片的地址，可以直接嵌入网页或 README 中
+    pub fn guild_badge_url(&self, guild_id: &str, style: BadgeStyle) -> String {
+        let guild_id = utf8_percent_encode(guild_id, NON_ALPHANUMERIC);
+        format!("{}/v3/badge/guild?guild_id={}&style={}", self.base_url(), guild_id, style as i32)
+    }
+
+    /// 获取服务器助力历史，`start_time` 和 `end_time` 为秒级时间戳
+    pub async fn get_guild_boost_history(
+        &self,
+        guild_id: &str,
+        start_time: Option<i64>,
+        end_time: Option<i64>,
+        params: &PageParams,
+    ) -> Result<PagedResponse<GuildBoost>, KookError> {
+        let start_time = start_time.map(|t| t.to_string());
+        let end_time = end_time.map(|t| t.to_string());
+        let mut query = vec![("guild_id", guild_id)];
+        if let Some(start_time) = &start_time {
+            query.push(("start_time", start_time));
+        }
+        if let Some(end_time) = &end_time {
+            query.push(("end_time", end_time));
+        }
+        self.paged_request(Method::GET, "/v3/guild-boost/history", params, Some(&query)).await
+    }
+}
@@ -0,0 +1,18 @@
+//! 亲密度相关接口
+use reqwest::Method;
+use crate::client::KookClient;
+use crate::models::*;
+
+impl KookClient {
+    /// 获取用户与机器人的亲密度
+    pub async fn get_intimacy(&self, user_id: &str) -> Result<Intimacy, KookError> {
+        let query = [("user_id", user_id)];
+        self.api_request(Method::GET, "/v3/intimacy/index", Some(&query), None).await
+    }
+
+    /// 更新用户与机器人的亲密度
+    pub async fn update_intimacy(&self, update: &UpdateIntimacy) -> Result<(), KookError> {
+        let body = serde_json::to_value(update)?;
+        self.api_action("/v3/intimacy/update", &body).await
+    }
+}
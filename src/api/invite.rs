@@ -0,0 +1,34 @@
+//! 邀请链接相关接口
+use reqwest::Method;
+use serde_json::json;
+use crate::client::{KookClient, PageParams};
+use crate::models::*;
+
+impl KookClient {
+    /// 获取服务器或频道的邀请链接列表
+    pub async fn get_invites(&self, target: &InviteTarget, params: &PageParams) -> Result<PagedResponse<Invite>, KookError> {
+        let query = [target.as_param()];
+        self.paged_request(Method::GET, "/v3/invite/list", params, Some(&query)).await
+    }
+
+    /// 创建邀请链接
+    pub async fn create_invite(
+        &self,
+        target: &InviteTarget,
+        duration: InviteDuration,
+        uses: InviteUses,
+    ) -> Result<InviteCreated, KookError> {
+        let (key, value) = target.as_param();
+        let mut body = json!({ "duration": duration as i64, "setting_times": uses as i32 });
+        body[key] = value.into();
+        self.api_request(Method::POST, "/v3/invite/create", None, Some(&body)).await
+    }
+
+    /// 删除邀请链接
+    pub async fn delete_invite(&self, target: &InviteTarget, url_code: &str) -> Result<(), KookError> {
+        let (key, value) = target.as_param();
+        let mut body = json!({ "url_code": url_code });
+        body[key] = value.into();
+        self.api_action("/v3/invite/delete", &body).await
+    }
+}
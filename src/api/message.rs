@@ -0,0 +1,161 @@
+//! 消息相关接口
+use futures_util::Stream;
+use reqwest::Method;
+use serde_json::json;
+use std::collections::{HashSet, VecDeque};
+use crate::client::{KookClient, PaginateOptions};
+use crate::models::*;
+
+impl KookClient {
+    /// 发送频道消息
+    pub async fn create_message(&self, message: &CreateMessage) -> Result<MessageCreated, KookError> {
+        let body = serde_json::to_value(message)?;
+        self.api_request(Method::POST, "/v3/message/create", None, Some(&body)).await
+    }
+
+    /// 发送频道文字消息
+    pub async fn send_channel_message(
+        &self,
+        channel_id: &str,
+        content: &str,
+    ) -> Result<MessageCreated, KookError> {
+        self.create_message(&CreateMessage::new(channel_id, content)).await
+    }
+
+    /// 使用服务端消息模板发送频道消息，`data` 为模板渲染所需的数据
+    pub async fn send_template_message(
+        &self,
+        target_id: &str,
+        template_id: &str,
+        message_type: MessageType,
+        data: &serde_json::Value,
+    ) -> Result<MessageCreated, KookError> {
+        let message = CreateMessage::new(target_id, &data.to_string())
+            .message_type(message_type)
+            .template_id(template_id);
+        self.create_message(&message).await
+    }
+
+    /// 获取频道聊天消息列表
+    pub async fn list_messages(
+        &self,
+        target_id: &str,
+        filter: &MessageListQuery,
+    ) -> Result<Vec<Message>, KookError> {
+        let mut query_params = vec![("target_id", target_id.to_string())];
+        query_params.extend(filter.to_query());
+
+        let query: Vec<(&str, &str)> = query_params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        let list: MessageList = self.api_request(Method::GET, "/v3/message/list", Some(&query), None).await?;
+        Ok(list.items)
+    }
+
+    /// 从 `msg_id` (为空时从最新的消息) 开始向前逐页获取历史消息，按从新到旧的顺序返回
+    pub fn paginate_messages<'a>(
+        &'a self,
+        target_id: &str,
+        msg_id: Option<&str>,
+        options: PaginateOptions,
+    ) -> impl Stream<Item = Result<Message, KookError>> + 'a {
+        struct HistoryState<'a> {
+            client: &'a KookClient,
+            target_id: String,
+            anchor: Option<String>,
+            options: PaginateOptions,
+            buffer: VecDeque<Message>,
+            seen: HashSet<String>,
+            yielded: usize,
+            done: bool,
+        }
+
+        let state = HistoryState {
+            client: self,
+            target_id: target_id.to_string(),
+            anchor: msg_id.map(str::to_string),
+            options,
+            buffer: VecDeque::new(),
+            seen: msg_id.map(str::to_string).into_iter().collect(),
+            yielded: 0,
+            done: false,
+        };
+
+        futures_util::stream::unfold(state, |mut state| async move {
+            loop {
+                if state.options.max_items.is_some_and(|max| state.yielded >= max) {
+                    return None;
+                }
+                if let Some(message) = state.buffer.pop_front() {
+                    state.yielded += 1;
+                    return Some((Ok(message), state));
+                }
+                if state.done {
+                    return None;
+                }
+
+                let filter = MessageListQuery {
+                    flag: state.anchor.as_ref().map(|_| MessageListFlag::Before),
+                    msg_id: state.anchor.clone(),
+                    page_size: state.options.page_size,
+                    pin: None,
+                };
+                match state.client.list_messages(&state.target_id, &filter).await {
+                    Ok(mut items) => {
+                        items.sort_by_key(|message| std::cmp::Reverse(message.create_at));
+                        let page_size = state.options.page_size.unwrap_or(50) as usize;
+                        state.done = items.len() < page_size;
+                        // 去掉已经返回过的消息 (包括参考消息)，整页都是重复消息时结束
+                        items.retain(|message| state.seen.insert(message.id.clone()));
+                        match items.last() {
+                            Some(oldest) => state.anchor = Some(oldest.id.clone()),
+                            None => state.done = true,
+                        }
+                        state.buffer.extend(items);
+                    }
+                    Err(e) => {
+                        state.done = true;
+                        return Some((Err(e), state));
+                    }
+                }
+            }
+        })
+    }
+
+    /// 获取频道消息详情
+    pub async fn get_message(&self, msg_id: &str) -> Result<Message, KookError> {
+        let query = [("msg_id", msg_id)];
+        self.api_request(Method::GET, "/v3/message/view", Some(&query), None).await
+    }
+
+    /// 编辑频道消息，目前只支持 KMarkdown 和卡片消息
+    pub async fn update_message(&self, update: &UpdateMessage) -> Result<(), KookError> {
+        let body = serde_json::to_value(update)?;
+        self.api_action("/v3/message/update", &body).await
+    }
+
+    /// 删除频道消息
+    pub async fn delete_message(&self, msg_id: &str) -> Result<(), KookError> {
+        let body = json!({ "msg_id": msg_id });
+        self.api_action("/v3/message/delete", &body).await
+    }
+
+    /// 获取回应了某个表情的用户列表
+    pub async fn get_reaction_users(&self, msg_id: &str, emoji: &str) -> Result<Vec<ReactionUser>, KookError> {
+        let query = [("msg_id", msg_id), ("emoji", emoji)];
+        self.api_request(Method::GET, "/v3/message/reaction-list", Some(&query), None).await
+    }
+
+    /// 给消息添加回应
+    pub async fn add_reaction(&self, msg_id: &str, emoji: &str) -> Result<(), KookError> {
+        let body = json!({ "msg_id": msg_id, "emoji": emoji });
+        self.api_action("/v3/message/add-reaction", &body).await
+    }
+
+    /// 删除消息的回应，`user_id` 为空时删除自己的回应
+    pub async fn delete_reaction(&self, msg_id: &str, emoji: &str, user_id: Option<&str>) -> Result<(), KookError> {
+        let mut body = json!({ "msg_id": msg_id, "emoji": emoji });
+        if let Some(user_id) = user_id {
+            body["user_id"] = user_id.into();
+        }
+        self.api_action("/v3/message/delete-reaction", &body).await
+    }
+}
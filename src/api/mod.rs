@@ -0,0 +1,17 @@
+//! 可选：可为常用接口提供包装，如发送消息
+pub mod asset;
+pub mod channel;
+pub mod direct_message;
+pub mod emoji;
+pub mod game;
+pub mod guild;
+pub mod intimacy;
+pub mod invite;
+pub mod message;
+pub mod role;
+pub mod template;
+pub mod user;
+pub mod voice;
+
+pub use asset::AssetUpload;
+pub use voice::VoiceSession;
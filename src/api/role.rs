@@ -0,0 +1,56 @@
+//! 服务器角色相关接口
+use futures_util::Stream;
+use reqwest::Method;
+use serde_json::json;
+use crate::client::{KookClient, PageParams, PaginateOptions};
+use crate::models::*;
+
+impl KookClient {
+    /// 获取服务器角色列表
+    pub async fn get_guild_roles(&self, guild_id: &str, params: &PageParams) -> Result<PagedResponse<Role>, KookError> {
+        let query = [("guild_id", guild_id)];
+        self.paged_request(Method::GET, "/v3/guild-role/list", params, Some(&query)).await
+    }
+
+    /// 自动分页获取服务器角色
+    pub fn paginate_guild_roles<'a>(
+        &'a self,
+        guild_id: &str,
+        options: PaginateOptions,
+    ) -> impl Stream<Item = Result<Role, KookError>> + 'a {
+        self.paginate("/v3/guild-role/list", &[("guild_id", guild_id)], options)
+    }
+
+    /// 创建服务器角色，`name` 为空时使用默认名称
+    pub async fn create_guild_role(&self, guild_id: &str, name: Option<&str>) -> Result<Role, KookError> {
+        let mut body = json!({ "guild_id": guild_id });
+        if let Some(name) = name {
+            body["name"] = name.into();
+        }
+        self.api_request(Method::POST, "/v3/guild-role/create", None, Some(&body)).await
+    }
+
+    /// 修改服务器角色
+    pub async fn update_guild_role(&self, update: &UpdateRole) -> Result<Role, KookError> {
+        let body = serde_json::to_value(update)?;
+        self.api_request(Method::POST, "/v3/guild-role/update", None, Some(&body)).await
+    }
+
+    /// 删除服务器角色
+    pub async fn delete_guild_role(&self, guild_id: &str, role_id: i32) -> Result<(), KookError> {
+        let body = json!({ "guild_id": guild_id, "role_id": role_id });
+        self.api_action("/v3/guild-role/delete", &body).await
+    }
+
+    /// 赋予用户角色
+    pub async fn grant_guild_role(&self, guild_id: &str, user_id: &str, role_id: i32) -> Result<RoleGrant, KookError> {
+        let body = json!({ "guild_id": guild_id, "user_id": user_id, "role_id": role_id });
+        self.api_request(Method::POST, "/v3/guild-role/grant", None, Some(&body)).await
+    }
+
+    /// 删除用户的角色
+    pub async fn revoke_guild_role(&self, guild_id: &str, user_id: &str, role_id: i32) -> Result<RoleGrant, KookError> {
+        let body = json!({ "guild_id": guild_id, "user_id": user_id, "role_id": role_id });
+        self.api_request(Method::POST, "/v3/guild-role/revoke", None, Some(&body)).await
+    }
+}
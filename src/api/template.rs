@@ -0,0 +1,33 @@
+//! 消息模板相关接口
+use reqwest::Method;
+use serde_json::json;
+use crate::client::{KookClient, PageParams};
+use crate::models::*;
+
+impl KookClient {
+    /// 获取消息模板列表
+    pub async fn get_templates(&self, params: &PageParams) -> Result<PagedResponse<MessageTemplate>, KookError> {
+        self.paged_request(Method::GET, "/v3/template/list", params, None).await
+    }
+
+    /// 创建消息模板，模板需要审核通过后才能使用
+    pub async fn create_template(&self, template: &SaveTemplate) -> Result<MessageTemplate, KookError> {
+        let body = serde_json::to_value(template)?;
+        self.api_request(Method::POST, "/v3/template/create", None, Some(&body)).await
+    }
+
+    /// 修改消息模板
+    pub async fn update_template(&self, template: &SaveTemplate) -> Result<MessageTemplate, KookError> {
+        if template.id.is_none() {
+            return Err(KookError::Params("修改消息模板需要模板 id".to_string()));
+        }
+        let body = serde_json::to_value(template)?;
+        self.api_request(Method::POST, "/v3/template/update", None, Some(&body)).await
+    }
+
+    /// 删除消息模板
+    pub async fn delete_template(&self, id: &str) -> Result<(), KookError> {
+        let body = json!({ "id": id });
+        self.api_action("/v3/template/delete", &body).await
+    }
+}
@@ -0,0 +1,29 @@
+//! 用户相关接口
+use reqwest::Method;
+use serde_json::json;
+use crate::client::KookClient;
+use crate::models::*;
+
+impl KookClient {
+    /// 获取用户详情
+    pub async fn get_user(&self, user_id: &str) -> Result<User, KookError> {
+        let query = [("user_id", user_id)];
+        self.api_request(Method::GET, "/v3/user/view", Some(&query), None).await
+    }
+
+    /// 获取用户在指定服务器中的详情，包括昵称、角色、加入时间和最后活跃时间
+    pub async fn get_guild_user(&self, guild_id: &str, user_id: &str) -> Result<GuildMember, KookError> {
+        let query = [("user_id", user_id), ("guild_id", guild_id)];
+        self.api_request(Method::GET, "/v3/user/view", Some(&query), None).await
+    }
+
+    /// 下线机器人，使用 WebSocket 连接时应在断开连接后调用
+    pub async fn go_offline(&self) -> Result<(), KookError> {
+        self.api_action("/v3/user/offline", &json!({})).await
+    }
+
+    /// 获取机器人的在线状态
+    pub async fn get_online_status(&self) -> Result<OnlineStatus, KookError> {
+        self.api_request(Method::GET, "/v3/user/get-online-status", None, None).await
+    }
+}
@@ -0,0 +1,125 @@
+//! 语音频道相关接口
+use reqwest::Method;
+use serde_json::json;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use crate::client::{KookClient, PageParams};
+use crate::models::*;
+
+impl KookClient {
+    /// 加入语音频道，返回 RTP 推流参数
+    ///
+    /// 加入后需要定期调用 [`KookClient::voice_keep_alive`]，也可以使用 [`KookClient::join_voice_session`]。
+    pub async fn join_voice(&self, join: &JoinVoice) -> Result<VoiceConnection, KookError> {
+        let body = serde_json::to_value(join)?;
+        self.api_request(Method::POST, "/v3/voice/join", None, Some(&body)).await
+    }
+
+    /// 获取机器人加入的语音频道列表
+    pub async fn get_voice_channels(&self, params: &PageParams) -> Result<PagedResponse<VoiceChannel>, KookError> {
+        self.paged_request(Method::GET, "/v3/voice/list", params, None).await
+    }
+
+    /// 离开语音频道
+    pub async fn leave_voice(&self, channel_id: &str) -> Result<(), KookError> {
+        let body = json!({ "channel_id": channel_id });
+        self.api_action("/v3/voice/leave", &body).await
+    }
+
+    /// 保持语音连接活跃，服务端在一段时间没有收到 keep-alive 后会断开推流
+    pub async fn voice_keep_alive(&self, channel_id: &str) -> Result<(), KookError> {
+        let body = json!({ "channel_id": channel_id });
+        self.api_action("/v3/voice/keep-alive", &body).await
+    }
+
+    /// 加入语音频道，并在后台定期发送 keep-alive
+    pub async fn join_voice_session(&self, join: &JoinVoice) -> Result<VoiceSession, KookError> {
+        let connection = self.join_voice(join).await?;
+        Ok(VoiceSession::start(self.clone(), join.channel_id.clone(), connection, VoiceSession::KEEP_ALIVE_INTERVAL))
+    }
+}
+
+/// 语音频道会话，在后台定期发送 keep-alive，drop 时自动离开频道
+///
+/// drop 时会在当前 tokio 运行时中发送离开请求，需要确认离开结果时请调用 [`VoiceSession::leave`]。
+pub struct VoiceSession {
+    client: KookClient,
+    channel_id: String,
+    connection: VoiceConnection,
+    keep_alive: Option<JoinHandle<()>>,
+}
+
+impl VoiceSession {
+    /// 默认的 keep-alive 间隔
+    pub const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(45);
+
+    /// keep-alive 间隔的下限
+    pub const MIN_KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(1);
+
+    /// 为已加入的语音频道启动 keep-alive 任务
+    ///
+    /// `interval` 小于 [`VoiceSession::MIN_KEEP_ALIVE_INTERVAL`] 时使用该下限。
+    pub fn start(client: KookClient, channel_id: String, connection: VoiceConnection, interval: Duration) -> Self {
+        let interval = interval.max(Self::MIN_KEEP_ALIVE_INTERVAL);
+        let task_client = client.clone();
+        let task_channel_id = channel_id.clone();
+        let keep_alive = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            // 第一次 tick 立即完成，加入频道时已经建立了连接
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                if let Err(e) = task_client.voice_keep_alive(&task_channel_id).await {
+                    log::warn!("语音频道 {} keep-alive 失败: {}", task_channel_id, e);
+                }
+            }
+        });
+
+        Self {
+            client,
+            channel_id,
+            connection,
+            keep_alive: Some(keep_alive),
+        }
+    }
+
+    /// 语音频道 id
+    pub fn channel_id(&self) -> &str {
+        &self.channel_id
+    }
+
+    /// RTP 推流参数
+    pub fn connection(&self) -> &VoiceConnection {
+        &self.connection
+    }
+
+    /// 停止 keep-alive 并离开语音频道
+    pub async fn leave(mut self) -> Result<(), KookError> {
+        if let Some(keep_alive) = self.keep_alive.take() {
+            keep_alive.abort();
+        }
+        self.client.leave_voice(&self.channel_id).await
+    }
+}
+
+impl Drop for VoiceSession {
+    fn drop(&mut self) {
+        let Some(keep_alive) = self.keep_alive.take() else {
+            return;
+        };
+        keep_alive.abort();
+
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => {
+                let client = self.client.clone();
+                let channel_id = self.channel_id.clone();
+                handle.spawn(async move {
+                    if let Err(e) = client.leave_voice(&channel_id).await {
+                        log::warn!("离开语音频道 {} 失败: {}", channel_id, e);
+                    }
+                });
+            }
+            Err(_) => log::warn!("没有可用的 tokio 运行时，无法自动离开语音频道 {}", self.channel_id),
+        }
+    }
+}
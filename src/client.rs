@@ -1,14 +1,32 @@
-use reqwest::{Client, Method, header::HeaderMap};
+use reqwest::{Client, Method, Proxy, RequestBuilder, StatusCode, header::{HeaderMap, HeaderName, HeaderValue}, multipart::Form};
+use std::collections::VecDeque;
 use std::env;
+use std::sync::Arc;
 use std::time::Duration;
 use serde_json::Value;
+use futures_util::Stream;
 use crate::models::*;
+use crate::ratelimit::{RateLimitInfo, RateLimiter};
+use crate::retry::RetryPolicy;
+use crate::middleware::{Middleware, RequestContext};
+use tokio::time::sleep;
+
+/// 默认的 API 地址
+pub const DEFAULT_BASE_URL: &str = "https://www.kookapp.cn/api";
+
+/// 默认的 User-Agent
+pub const DEFAULT_USER_AGENT: &str = "KookSDK/0.1.0";
 
 /// 核心客户端，管理 HTTP 客户端和 Bot Token
+#[derive(Clone)]
 pub struct KookClient {
     client: Client,
     bot_token: String,
     base_url: String,
+    proxy: Option<String>,
+    rate_limiter: Arc<RateLimiter>,
+    retry_policy: RetryPolicy,
+    middlewares: Arc<Vec<Arc<dyn Middleware>>>,
 }
 
 /// 分页参数
@@ -19,25 +37,214 @@ pub struct PageParams {
     pub sort: Option<String>,
 }
 
-impl KookClient {
-    /// 使用指定的 token 创建客户端
-    pub fn with_token(token: &str) -> Result<Self, KookError> {
+impl PageParams {
+    /// 转换为查询参数
+    pub fn to_query(&self) -> Vec<(&str, String)> {
+        let mut query = Vec::new();
+        if let Some(page) = self.page {
+            query.push(("page", page.to_string()));
+        }
+        if let Some(page_size) = self.page_size {
+            query.push(("page_size", page_size.to_string()));
+        }
+        if let Some(sort) = &self.sort {
+            query.push(("sort", sort.clone()));
+        }
+        query
+    }
+}
+
+/// 自动分页参数
+#[derive(Debug, Clone, Default)]
+pub struct PaginateOptions {
+    /// 起始页码，默认为 1
+    pub start_page: Option<i32>,
+    /// 每页数据量，默认由服务端决定
+    pub page_size: Option<i32>,
+    /// 最多返回的数据条数
+    pub max_items: Option<usize>,
+    /// 排序字段
+    pub sort: Option<String>,
+}
+
+/// 自动分页的内部状态
+struct PageState<'a, T> {
+    client: &'a KookClient,
+    path: String,
+    query: Vec<(String, String)>,
+    options: PaginateOptions,
+    next_page: Option<i32>,
+    buffer: VecDeque<T>,
+    yielded: usize,
+}
+
+/// 客户端构建器，用于自定义 API 地址、超时、代理和请求头
+#[derive(Clone)]
+pub struct KookClientBuilder {
+    bot_token: String,
+    base_url: String,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    proxy: Option<String>,
+    https_only: bool,
+    user_agent: String,
+    accept_language: Option<String>,
+    headers: Vec<(String, String)>,
+    retry_policy: RetryPolicy,
+    middlewares: Vec<Arc<dyn Middleware>>,
+}
+
+impl KookClientBuilder {
+    /// 使用指定的 token 创建构建器
+    pub fn new(token: &str) -> Self {
+        Self {
+            bot_token: token.to_string(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            timeout: Some(Duration::from_secs(30)),
+            connect_timeout: None,
+            proxy: None,
+            https_only: true,
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            accept_language: None,
+            headers: Vec::new(),
+            retry_policy: RetryPolicy::default(),
+            middlewares: Vec::new(),
+        }
+    }
+
+    /// 设置 API 地址，例如指向本地的模拟服务器
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// 设置单个请求的超时时间，`None` 表示不限制
+    pub fn timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// 设置建立连接的超时时间
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// 设置代理地址，支持 `http://`、`socks5://` 和 `socks5h://`，
+    /// 同时用于 REST 请求和 WebSocket 网关连接
+    pub fn proxy(mut self, proxy_url: &str) -> Self {
+        self.proxy = Some(proxy_url.to_string());
+        self
+    }
+
+    /// 是否只允许 HTTPS 请求 (默认开启)
+    pub fn https_only(mut self, enabled: bool) -> Self {
+        self.https_only = enabled;
+        self
+    }
+
+    /// 设置 User-Agent
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = user_agent.to_string();
+        self
+    }
+
+    /// 设置 Accept-Language，API 会据此返回对应语言的错误消息
+    pub fn accept_language(mut self, language: &str) -> Self {
+        self.accept_language = Some(language.to_string());
+        self
+    }
+
+    /// 添加一个默认请求头，会附加在每个请求上
+    pub fn default_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// 设置 REST 请求的重试策略，`RetryPolicy::none()` 表示不重试
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// 添加一个请求中间件，按添加顺序执行
+    pub fn middleware(mut self, middleware: impl Middleware) -> Self {
+        self.middlewares.push(Arc::new(middleware));
+        self
+    }
+
+    /// 构建客户端
+    pub fn build(self) -> Result<KookClient, KookError> {
+        if self.https_only && !self.base_url.starts_with("https://") {
+            return Err(KookError::Params(format!(
+                "base_url {} 不是 HTTPS 地址，如需使用 HTTP 请关闭 https_only", self.base_url
+            )));
+        }
+
         let mut headers = HeaderMap::new();
-        headers.insert("User-Agent", "KookSDK/0.1.0".parse().unwrap());
-        
-        let client = Client::builder()
-            .https_only(true)
-            .timeout(Duration::from_secs(30))
-            .default_headers(headers)
-            .build()
-            .map_err(|e| KookError::Network(e.to_string()))?;
-            
+        headers.insert("User-Agent", header_value(&self.user_agent)?);
+        if let Some(language) = &self.accept_language {
+            headers.insert("Accept-Language", header_value(language)?);
+        }
+        for (name, value) in &self.headers {
+            let name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|e| KookError::Params(format!("无效的请求头名称 {}: {}", name, e)))?;
+            headers.insert(name, header_value(value)?);
+        }
+
+        let mut builder = Client::builder()
+            .https_only(self.https_only)
+            .default_headers(headers);
+
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(proxy_url) = &self.proxy {
+            // WebSocket 网关连接只支持这些代理类型，提前在构建时报错
+            let scheme = proxy_url.split_once("://").map(|(scheme, _)| scheme.to_ascii_lowercase());
+            if !matches!(scheme.as_deref(), Some("http" | "socks5" | "socks5h")) {
+                return Err(KookError::Params(format!(
+                    "不支持的代理地址 {}，仅支持 http://、socks5:// 和 socks5h://", proxy_url
+                )));
+            }
+            let proxy = Proxy::all(proxy_url)
+                .map_err(|e| KookError::Params(format!("无效的代理地址 {}: {}", proxy_url, e)))?;
+            builder = builder.proxy(proxy);
+        }
+
+        let client = builder.build()?;
+
         Ok(KookClient {
             client,
-            bot_token: token.to_string(),
-            base_url: "https://www.kookapp.cn/api".to_string(),
+            bot_token: self.bot_token,
+            base_url: self.base_url,
+            proxy: self.proxy,
+            rate_limiter: Arc::new(RateLimiter::new()),
+            retry_policy: self.retry_policy,
+            middlewares: Arc::new(self.middlewares),
         })
     }
+}
+
+/// 校验并转换请求头的值
+fn header_value(value: &str) -> Result<HeaderValue, KookError> {
+    HeaderValue::from_str(value)
+        .map_err(|e| KookError::Params(format!("无效的请求头 {}: {}", value, e)))
+}
+
+impl KookClient {
+    /// 创建客户端构建器
+    pub fn builder(token: &str) -> KookClientBuilder {
+        KookClientBuilder::new(token)
+    }
+
+    /// 使用指定的 token 创建客户端
+    pub fn with_token(token: &str) -> Result<Self, KookError> {
+        Self::builder(token).build()
+    }
 
     /// 从环境变量初始化客户端
     pub fn new() -> Result<Self, KookError> {
@@ -46,12 +253,32 @@ impl KookClient {
         Self::with_token(&bot_token)
     }
 
+    /// 获取 API 地址
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// 获取代理地址 (WebSocket 连接同样使用该代理)
+    pub fn proxy(&self) -> Option<&str> {
+        self.proxy.as_deref()
+    }
+
     /// 获取 Bot Token (用于 WebSocket 连接)
     pub fn get_token(&self) -> &str {
         &self.bot_token
     }
 
-    /// 通用 API 请求方法，包含完整的错误处理
+    /// 获取速率限制器 (克隆出的客户端共享同一个限制器)
+    pub fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    /// 获取重试策略
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// 通用 API 请求方法，包含完整的错误处理，并按照重试策略自动重试
     pub async fn api_request<T: serde::de::DeserializeOwned>(
         &self,
         method: Method,
@@ -59,48 +286,162 @@ impl KookClient {
         query: Option<&[(&str, &str)]>,
         body: Option<&Value>,
     ) -> Result<T, KookError> {
-        let url = if path.starts_with('/') {
-            format!("{}{}", self.base_url, path)
-        } else {
-            format!("{}/{}", self.base_url, path)
+        let ctx = RequestContext::new(method, path, query, body);
+        let data = self.dispatch(ctx, None).await?;
+        decode_data(path, data)
+    }
+
+    /// 发送不关心返回数据的 POST 请求，例如删除、踢出等操作
+    pub async fn api_action(&self, path: &str, body: &Value) -> Result<(), KookError> {
+        let ctx = RequestContext::new(Method::POST, path, None, Some(body));
+        self.dispatch(ctx, None).await.map(|_| ())
+    }
+
+    /// 发送 multipart/form-data 请求
+    ///
+    /// 表单中可能包含无法重放的数据流，因此不会自动重试。
+    pub async fn multipart_request<T: serde::de::DeserializeOwned>(
+        &self,
+        path: &str,
+        form: Form,
+    ) -> Result<T, KookError> {
+        let ctx = RequestContext::new(Method::POST, path, None, None);
+        let data = self.dispatch(ctx, Some(form)).await?;
+        decode_data(path, data)
+    }
+
+    /// 依次经过中间件后发送请求
+    async fn dispatch(&self, mut ctx: RequestContext, form: Option<Form>) -> Result<Value, KookError> {
+        // 只有执行过 before_request 的中间件才会收到 after_response
+        let mut seen = 0;
+        let mut short_circuit = None;
+        for middleware in self.middlewares.iter() {
+            seen += 1;
+            short_circuit = middleware.before_request(&mut ctx);
+            if short_circuit.is_some() {
+                break;
+            }
+        }
+
+        let result = match (short_circuit, form) {
+            (Some(result), _) => result,
+            (None, Some(form)) => self.send_multipart(&ctx, form).await,
+            (None, None) => self.send_with_retry(&ctx).await,
         };
 
-        let mut req = self.client
-            .request(method, &url)
-            .header("Authorization", format!("Bot {}", self.bot_token))
-            .header("Content-Type", "application/json");
+        for middleware in self.middlewares[..seen].iter().rev() {
+            middleware.after_response(&ctx, &result);
+        }
+        result
+    }
+
+    /// 按照重试策略发送请求
+    ///
+    /// 触发限速 (HTTP 429) 时请求不会被服务端处理，因此任何方法都可以重试，
+    /// 等待时间取自响应头中的重置时间；其余错误只对可以安全重发的请求重试。
+    /// 第一次限速后的等待不占用重试次数，即使策略不重试也会等待重置后再发送一次。
+    async fn send_with_retry(&self, ctx: &RequestContext) -> Result<Value, KookError> {
+        let policy = &self.retry_policy;
+        let retryable_request = policy.is_retryable_request(&ctx.method);
+        let mut attempt = 1;
+        let mut rate_limit_waited = false;
+
+        loop {
+            let (result, rate_limit) = self.send_request(ctx).await;
+            let err = match result {
+                Err(e) => e,
+                result => return result,
+            };
 
-        if let Some(q) = query {
-            req = req.query(q);
+            let rate_limit = rate_limit.filter(|_| is_rate_limited(&err) && policy.retry_on_rate_limit);
+            if let Some(info) = rate_limit.as_ref().filter(|_| !rate_limit_waited) {
+                rate_limit_waited = true;
+                let delay = info.retry_after();
+                log::warn!("请求 {} 被限速，{:?} 后重试: {}", ctx.path, delay, err);
+                sleep(delay).await;
+                continue;
+            }
+            if attempt >= policy.max_attempts || !policy.is_retryable_error(&err) {
+                return Err(err);
+            }
+
+            let delay = match rate_limit {
+                Some(info) => info.retry_after(),
+                None if retryable_request => policy.backoff(attempt),
+                None => return Err(err),
+            };
+            log::warn!("请求 {} 失败，{:?} 后重试 ({}/{}): {}", ctx.path, delay, attempt, policy.max_attempts - 1, err);
+            sleep(delay).await;
+            attempt += 1;
         }
-        
-        if let Some(b) = body {
+    }
+
+    /// 发送单次请求 (不含重试)，同时返回响应头中的限速信息
+    async fn send_request(&self, ctx: &RequestContext) -> (Result<Value, KookError>, Option<RateLimitInfo>) {
+        let path = ctx.path.as_str();
+        let mut req = self.request(ctx)
+            .header("Content-Type", "application/json");
+
+        if let Some(b) = &ctx.body {
             req = req.json(b);
         }
 
-        let resp = req.send().await
-            .map_err(|e| KookError::Network(e.to_string()))?;
+        match self.execute(path, req).await {
+            Ok((status, rate_limit, response_text)) => {
+                (parse_response(path, status, response_text), Some(rate_limit))
+            }
+            Err(e) => (Err(e), None),
+        }
+    }
 
-        let status = resp.status();
-        let response_text = resp.text().await
-            .map_err(|e| KookError::Network(e.to_string()))?;
+    /// 发送单次 multipart 请求
+    async fn send_multipart(&self, ctx: &RequestContext, form: Form) -> Result<Value, KookError> {
+        let req = self.request(ctx).multipart(form);
+        let (status, _, response_text) = self.execute(&ctx.path, req).await?;
+        parse_response(&ctx.path, status, response_text)
+    }
+
+    /// 根据请求信息创建带有认证信息的请求
+    fn request(&self, ctx: &RequestContext) -> RequestBuilder {
+        let url = if ctx.path.starts_with('/') {
+            format!("{}{}", self.base_url, ctx.path)
+        } else {
+            format!("{}/{}", self.base_url, ctx.path)
+        };
 
-        // 处理 HTTP 错误状态
-        if !status.is_success() {
-            return Err(KookError::Network(format!("HTTP {}: {}", status, response_text)));
+        let mut req = self.client
+            .request(ctx.method.clone(), url)
+            .header("Authorization", format!("Bot {}", self.bot_token));
+
+        if !ctx.query.is_empty() {
+            req = req.query(&ctx.query);
         }
+        for (name, value) in &ctx.headers {
+            req = req.header(name.as_str(), value.as_str());
+        }
+        req
+    }
 
-        // 解析 JSON 响应
-        let api_resp: ApiResponse<T> = serde_json::from_str(&response_text)
-            .map_err(|e| KookError::Json(format!("Failed to parse response: {} - Response: {}", e, response_text)))?;
+    /// 等待限速后发送请求并读取响应，同时根据响应头更新限速状态
+    async fn execute(
+        &self,
+        path: &str,
+        req: RequestBuilder,
+    ) -> Result<(StatusCode, RateLimitInfo, String), KookError> {
+        self.rate_limiter.acquire(path).await;
 
-        // 检查 API 错误码
-        if api_resp.code != 0 {
-            return Err(KookError::from_code(api_resp.code, api_resp.message));
+        let resp = req.send().await?;
+        let status = resp.status();
+        let rate_limit = RateLimitInfo::from_headers(resp.headers());
+
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            self.rate_limiter.mark_exhausted(path, &rate_limit);
+        } else {
+            self.rate_limiter.update(path, &rate_limit);
         }
 
-        // 返回数据
-        api_resp.data.ok_or_else(|| KookError::Json("Response data is null".to_string()))
+        let response_text = resp.text().await?;
+        Ok((status, rate_limit, response_text))
     }
 
     /// 分页请求方法
@@ -111,31 +452,81 @@ impl KookClient {
         params: &PageParams,
         extra_query: Option<&[(&str, &str)]>,
     ) -> Result<PagedResponse<T>, KookError> {
-        let mut query_params = Vec::new();
-        
-        if let Some(page) = params.page {
-            query_params.push(("page", page.to_string()));
-        }
-        if let Some(page_size) = params.page_size {
-            query_params.push(("page_size", page_size.to_string()));
-        }
-        if let Some(sort) = &params.sort {
-            query_params.push(("sort", sort.clone()));
-        }
-        
+        let mut query_params = params.to_query();
+
         if let Some(extra) = extra_query {
             for (k, v) in extra {
-                query_params.push((k, v.to_string()));
+                query_params.push((*k, v.to_string()));
             }
         }
 
         let query_refs: Vec<(&str, &str)> = query_params.iter()
-            .map(|(k, v)| (k.as_ref(), v.as_ref()))
+            .map(|(k, v)| (*k, v.as_str()))
             .collect();
 
         self.api_request(method, path, Some(&query_refs), None).await
     }
 
+    /// 自动分页，返回按需逐页请求的数据流
+    ///
+    /// 根据响应中的 `meta.page_total` 判断是否还有下一页，遇到错误时产出错误并结束。
+    pub fn paginate<'a, T>(
+        &'a self,
+        path: &str,
+        extra_query: &[(&str, &str)],
+        options: PaginateOptions,
+    ) -> impl Stream<Item = Result<T, KookError>> + 'a
+    where
+        T: serde::de::DeserializeOwned + 'a,
+    {
+        let state = PageState {
+            client: self,
+            path: path.to_string(),
+            query: extra_query.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+            next_page: Some(options.start_page.unwrap_or(1)),
+            options,
+            buffer: VecDeque::new(),
+            yielded: 0,
+        };
+
+        futures_util::stream::unfold(state, |mut state| async move {
+            loop {
+                if state.options.max_items.is_some_and(|max| state.yielded >= max) {
+                    return None;
+                }
+                if let Some(item) = state.buffer.pop_front() {
+                    state.yielded += 1;
+                    return Some((Ok(item), state));
+                }
+
+                let page = state.next_page?;
+                let params = PageParams {
+                    page: Some(page),
+                    page_size: state.options.page_size,
+                    sort: state.options.sort.clone(),
+                };
+                let query_refs: Vec<(&str, &str)> = state.query.iter()
+                    .map(|(k, v)| (k.as_str(), v.as_str()))
+                    .collect();
+
+                match state.client.paged_request::<T>(Method::GET, &state.path, &params, Some(&query_refs)).await {
+                    Ok(resp) => {
+                        state.next_page = if page < resp.meta.page_total && !resp.items.is_empty() {
+                            Some(page + 1)
+                        } else {
+                            None
+                        };
+                        state.buffer.extend(resp.items);
+                    }
+                    Err(e) => {
+                        state.next_page = None;
+                        return Some((Err(e), state));
+                    }
+                }
+            }
+        })
+    }
+
     /// 获取当前用户信息
     pub async fn get_me(&self) -> Result<User, KookError> {
         self.api_request(Method::GET, "/v3/user/me", None, None).await
@@ -158,27 +549,99 @@ impl KookClient {
         self.paged_request(Method::GET, "/v3/guild/list", params, None).await
     }
 
+    /// 自动分页获取服务器中的频道
+    pub fn paginate_channels<'a>(
+        &'a self,
+        guild_id: &str,
+        options: PaginateOptions,
+    ) -> impl Stream<Item = Result<Channel, KookError>> + 'a {
+        self.paginate("/v3/channel/list", &[("guild_id", guild_id)], options)
+    }
+
+    /// 自动分页获取服务器列表
+    pub fn paginate_guilds(
+        &self,
+        options: PaginateOptions,
+    ) -> impl Stream<Item = Result<Guild, KookError>> + '_ {
+        self.paginate("/v3/guild/list", &[], options)
+    }
+
     /// 发送消息
     pub async fn send_message(
         &self,
         target_id: &str,
         content: &str,
-        message_type: Option<i32>,
+        message_type: Option<MessageType>,
         quote: Option<&str>,
-    ) -> Result<serde_json::Value, KookError> {
-        let mut body = serde_json::json!({
-            "target_id": target_id,
-            "content": content,
-        });
-
-        if let Some(msg_type) = message_type {
-            body["type"] = msg_type.into();
-        }
+    ) -> Result<MessageCreated, KookError> {
+        let mut message = CreateMessage::new(target_id, content)
+            .message_type(message_type.unwrap_or_default());
 
         if let Some(quote_id) = quote {
-            body["quote"] = quote_id.into();
+            message = message.quote(quote_id);
         }
 
-        self.api_request(Method::POST, "/v3/message/create", None, Some(&body)).await
+        self.create_message(&message).await
     }
 }
+
+/// 解析 API 响应，处理 HTTP 错误状态和 KOOK 错误码
+fn parse_response(
+    path: &str,
+    status: StatusCode,
+    response_text: String,
+) -> Result<Value, KookError> {
+    // 处理 HTTP 错误状态
+    if !status.is_success() {
+        // 错误响应通常也带有 KOOK 的错误码，优先使用
+        let envelope = serde_json::from_str::<ApiResponse<Value>>(&response_text).ok();
+        return Err(KookError::Api(ApiError {
+            kind: match &envelope {
+                Some(resp) if resp.code != 0 => ApiErrorKind::from_code(resp.code),
+                _ => ApiErrorKind::from_status(status.as_u16()),
+            },
+            code: envelope.as_ref().map(|resp| resp.code).filter(|code| *code != 0),
+            message: envelope.map(|resp| resp.message)
+                .unwrap_or_else(|| status.canonical_reason().unwrap_or("Unknown").to_string()),
+            status: Some(status.as_u16()),
+            path: Some(path.to_string()),
+            body: Some(response_text),
+        }));
+    }
+
+    // 解析 JSON 响应
+    let api_resp: ApiResponse<Value> = serde_json::from_str(&response_text)
+        .map_err(|e| KookError::Json(format!("Failed to parse response of {}: {} - Response: {}", path, e, response_text)))?;
+
+    // 检查 API 错误码
+    if api_resp.code != 0 {
+        return Err(KookError::Api(ApiError {
+            kind: ApiErrorKind::from_code(api_resp.code),
+            code: Some(api_resp.code),
+            message: api_resp.message,
+            status: Some(status.as_u16()),
+            path: Some(path.to_string()),
+            body: Some(response_text),
+        }));
+    }
+
+    // 返回数据，没有数据的接口 data 可能为 null
+    Ok(api_resp.data.unwrap_or(Value::Null))
+}
+
+/// 是否为限速错误
+fn is_rate_limited(err: &KookError) -> bool {
+    err.kind() == Some(ApiErrorKind::RateLimited)
+}
+
+/// 将响应中的 data 转换为目标类型
+fn decode_data<T: serde::de::DeserializeOwned>(path: &str, data: Value) -> Result<T, KookError> {
+    let is_null = data.is_null();
+    serde_json::from_value(data).map_err(|e| {
+        if is_null {
+            KookError::Json(format!("Response data of {} is null", path))
+        } else {
+            KookError::Json(format!("Failed to parse response data of {}: {}", path, e))
+        }
+    })
+}
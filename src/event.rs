@@ -0,0 +1,470 @@
+//! 强类型的网关/Webhook 事件
+//!
+//! 将 `EventData` 按照 `type` 和 `extra.type` 解析为 [`Event`]，
+//! 无法识别或解析失败的事件保留为 [`Event::Raw`]。
+use serde::{Deserialize, Deserializer, Serialize};
+use crate::models::{Attachment, EventData, MessageType, Quote, ReactionEmoji, User};
+
+/// 网关或 Webhook 推送的事件
+#[derive(Debug, Clone)]
+pub enum Event {
+    /// 消息事件 (文字、图片、视频、文件、音频、KMarkdown、卡片)
+    Message(MessageEvent),
+    /// 系统事件 (type 为 255)
+    System(SystemEvent),
+    /// 无法识别的事件，保留原始数据
+    Raw(EventData),
+}
+
+impl From<EventData> for Event {
+    fn from(data: EventData) -> Self {
+        match MessageType::from(data.r#type) {
+            MessageType::System => match serde_json::from_value::<SystemEventKind>(data.extra.clone()) {
+                Ok(kind) => Event::System(SystemEvent {
+                    channel_type: data.channel_type,
+                    target_id: data.target_id,
+                    msg_id: data.msg_id,
+                    msg_timestamp: data.msg_timestamp,
+                    kind,
+                }),
+                Err(e) => {
+                    log::debug!("无法解析系统事件 {} 的 extra: {}", data.msg_id, e);
+                    Event::Raw(data)
+                }
+            },
+            MessageType::Other(_) => Event::Raw(data),
+            message_type => match serde_json::from_value::<MessageExtra>(data.extra.clone()) {
+                Ok(extra) => Event::Message(MessageEvent {
+                    message_type,
+                    channel_type: data.channel_type,
+                    target_id: data.target_id,
+                    author_id: data.author_id,
+                    content: data.content,
+                    msg_id: data.msg_id,
+                    msg_timestamp: data.msg_timestamp,
+                    nonce: data.nonce,
+                    extra: Box::new(extra),
+                }),
+                Err(e) => {
+                    log::debug!("无法解析消息事件 {} 的 extra: {}", data.msg_id, e);
+                    Event::Raw(data)
+                }
+            },
+        }
+    }
+}
+
+/// 消息事件
+#[derive(Debug, Clone)]
+pub struct MessageEvent {
+    /// 消息类型
+    pub message_type: MessageType,
+    /// 消息通道类型，GROUP 为频道消息，PERSON 为私聊消息
+    pub channel_type: String,
+    /// 频道消息为频道 id，私聊消息为接收者的用户 id
+    pub target_id: String,
+    /// 发送者 id
+    pub author_id: String,
+    /// 消息内容
+    pub content: String,
+    /// 消息 id
+    pub msg_id: String,
+    /// 消息发送时间 (毫秒时间戳)
+    pub msg_timestamp: i64,
+    /// 随机串
+    pub nonce: String,
+    /// 附加数据
+    pub extra: Box<MessageExtra>,
+}
+
+impl MessageEvent {
+    /// 是否为私聊消息
+    pub fn is_direct_message(&self) -> bool {
+        self.channel_type == "PERSON"
+    }
+
+    /// 消息作者
+    pub fn author(&self) -> &User {
+        &self.extra.author
+    }
+
+    /// 作者在服务器中的昵称，没有设置昵称时为用户名
+    pub fn author_name(&self) -> &str {
+        let author = &self.extra.author;
+        if author.nickname.is_empty() {
+            &author.username
+        } else {
+            &author.nickname
+        }
+    }
+
+    /// 消息所在的服务器 id，私聊消息为 None
+    pub fn guild_id(&self) -> Option<&str> {
+        self.extra.guild_id.as_deref()
+    }
+
+    /// 是否提及了指定用户 (包括 @全体成员 和 @在线成员)
+    pub fn mentions(&self, user_id: &str) -> bool {
+        self.extra.mention_all || self.extra.mention_here || self.extra.mention.iter().any(|id| id == user_id)
+    }
+
+    /// 去掉 KMarkdown 语法的原始文本，非 KMarkdown 消息为消息内容
+    pub fn raw_content(&self) -> &str {
+        self.extra.kmarkdown.as_ref()
+            .map(|kmarkdown| kmarkdown.raw_content.as_str())
+            .unwrap_or(&self.content)
+    }
+}
+
+/// 消息事件的附加数据
+///
+/// 只有 `type` 和 `author` 是必需的，其余字段缺失或格式不符时使用默认值，
+/// 避免接口的细微变化导致消息事件变为 [`Event::Raw`]。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageExtra {
+    /// 消息类型
+    pub r#type: MessageType,
+    /// 服务器 id，私聊消息没有该字段
+    #[serde(default, deserialize_with = "default_on_error")]
+    pub guild_id: Option<String>,
+    /// 频道名称，私聊消息没有该字段
+    #[serde(default, deserialize_with = "default_on_error")]
+    pub channel_name: Option<String>,
+    /// 私信会话 Code，仅私聊消息有该字段
+    #[serde(default, deserialize_with = "default_on_error")]
+    pub code: Option<String>,
+    /// 发送者信息
+    pub author: User,
+    /// 提及的用户 id
+    #[serde(default, deserialize_with = "default_on_error")]
+    pub mention: Vec<String>,
+    /// 是否提及全体成员
+    #[serde(default, deserialize_with = "default_on_error")]
+    pub mention_all: bool,
+    /// 提及的角色 id，接口可能以整数或字符串返回
+    #[serde(default, deserialize_with = "role_ids")]
+    pub mention_roles: Vec<i32>,
+    /// 是否提及在线成员
+    #[serde(default, deserialize_with = "default_on_error")]
+    pub mention_here: bool,
+    /// KMarkdown 消息的解析结果
+    #[serde(default, deserialize_with = "default_on_error")]
+    pub kmarkdown: Option<KMarkdownInfo>,
+    /// 引用的消息
+    #[serde(default, deserialize_with = "default_on_error")]
+    pub quote: Option<Box<Quote>>,
+    /// 附件
+    #[serde(default, deserialize_with = "default_on_error")]
+    pub attachments: Option<Attachment>,
+}
+
+/// 解析失败时使用默认值
+fn default_on_error<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: serde::de::DeserializeOwned + Default,
+{
+    let value = serde_json::Value::deserialize(deserializer)?;
+    Ok(serde_json::from_value(value.clone()).unwrap_or_else(|e| {
+        log::debug!("忽略无法解析的字段 {}: {}", value, e);
+        T::default()
+    }))
+}
+
+/// 解析整数或字符串形式的角色 id，忽略无法识别的项
+fn role_ids<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<i32>, D::Error> {
+    let values: Vec<serde_json::Value> = default_on_error(deserializer)?;
+    Ok(values.iter()
+        .filter_map(|value| match value {
+            serde_json::Value::String(text) => text.parse().ok(),
+            value => value.as_i64().and_then(|id| i32::try_from(id).ok()),
+        })
+        .collect())
+}
+
+/// KMarkdown 消息的解析结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KMarkdownInfo {
+    /// 去掉语法后的原始文本
+    #[serde(default)]
+    pub raw_content: String,
+    /// 提及的用户
+    #[serde(default)]
+    pub mention_part: Vec<serde_json::Value>,
+    /// 提及的角色
+    #[serde(default)]
+    pub mention_role_part: Vec<serde_json::Value>,
+}
+
+/// 系统事件
+#[derive(Debug, Clone)]
+pub struct SystemEvent {
+    /// 消息通道类型
+    pub channel_type: String,
+    /// 服务器事件为服务器 id，私聊事件为用户 id
+    pub target_id: String,
+    /// 事件 id
+    pub msg_id: String,
+    /// 事件发生时间 (毫秒时间戳)
+    pub msg_timestamp: i64,
+    /// 事件内容
+    pub kind: SystemEventKind,
+}
+
+/// 系统事件的具体内容，对应 `extra.type` 和 `extra.body`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "body", rename_all = "snake_case")]
+pub enum SystemEventKind {
+    /// 新成员加入服务器
+    JoinedGuild(GuildMemberJoined),
+    /// 成员退出服务器
+    ExitedGuild(GuildMemberExited),
+    /// 服务器成员信息更新
+    UpdatedGuildMember(GuildMemberUpdated),
+    /// 服务器成员上线
+    GuildMemberOnline(GuildMemberPresence),
+    /// 服务器成员下线
+    GuildMemberOffline(GuildMemberPresence),
+    /// 频道消息被添加回应
+    AddedReaction(ReactionEvent),
+    /// 频道消息的回应被取消
+    DeletedReaction(ReactionEvent),
+    /// 频道消息被更新
+    UpdatedMessage(MessageUpdated),
+    /// 频道消息被删除
+    DeletedMessage(MessageDeleted),
+    /// 频道消息被置顶
+    PinnedMessage(MessagePinned),
+    /// 频道消息被取消置顶
+    UnpinnedMessage(MessagePinned),
+    /// 私聊消息被添加回应
+    PrivateAddedReaction(PrivateReactionEvent),
+    /// 私聊消息的回应被取消
+    PrivateDeletedReaction(PrivateReactionEvent),
+    /// 私聊消息被更新
+    UpdatedPrivateMessage(PrivateMessageUpdated),
+    /// 私聊消息被删除
+    DeletedPrivateMessage(PrivateMessageDeleted),
+    /// 用户加入语音频道
+    JoinedChannel(ChannelJoined),
+    /// 用户退出语音频道
+    ExitedChannel(ChannelExited),
+    /// 用户信息更新
+    UserUpdated(UserUpdated),
+    /// 机器人加入服务器
+    SelfJoinedGuild(SelfGuildEvent),
+    /// 机器人退出服务器
+    SelfExitedGuild(SelfGuildEvent),
+    /// 卡片消息中的按钮被点击
+    MessageBtnClick(Box<MessageBtnClick>),
+}
+
+/// 新成员加入服务器
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildMemberJoined {
+    /// 用户 id
+    pub user_id: String,
+    /// 加入时间 (毫秒时间戳)
+    #[serde(default)]
+    pub joined_at: i64,
+}
+
+/// 成员退出服务器
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildMemberExited {
+    /// 用户 id
+    pub user_id: String,
+    /// 退出时间 (毫秒时间戳)
+    #[serde(default)]
+    pub exited_at: i64,
+}
+
+/// 服务器成员信息更新
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildMemberUpdated {
+    /// 用户 id
+    pub user_id: String,
+    /// 新的昵称
+    #[serde(default)]
+    pub nickname: String,
+}
+
+/// 服务器成员上线/下线
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildMemberPresence {
+    /// 用户 id
+    pub user_id: String,
+    /// 事件发生时间 (毫秒时间戳)
+    #[serde(default)]
+    pub event_time: i64,
+    /// 与机器人共同所在的服务器 id
+    #[serde(default)]
+    pub guilds: Vec<String>,
+}
+
+/// 频道消息的回应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReactionEvent {
+    /// 消息 id
+    pub msg_id: String,
+    /// 回应的用户 id
+    pub user_id: String,
+    /// 频道 id
+    pub channel_id: String,
+    /// 表情
+    pub emoji: ReactionEmoji,
+}
+
+/// 频道消息更新
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageUpdated {
+    /// 消息 id
+    pub msg_id: String,
+    /// 新的消息内容
+    #[serde(default)]
+    pub content: String,
+    /// 频道 id
+    pub channel_id: String,
+    /// 提及的用户 id
+    #[serde(default)]
+    pub mention: Vec<String>,
+    /// 是否提及全体成员
+    #[serde(default)]
+    pub mention_all: bool,
+    /// 是否提及在线成员
+    #[serde(default)]
+    pub mention_here: bool,
+    /// 提及的角色 id
+    #[serde(default)]
+    pub mention_roles: Vec<i32>,
+    /// 更新时间 (毫秒时间戳)
+    #[serde(default)]
+    pub updated_at: i64,
+}
+
+/// 频道消息删除
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageDeleted {
+    /// 消息 id
+    pub msg_id: String,
+    /// 频道 id
+    pub channel_id: String,
+}
+
+/// 频道消息置顶/取消置顶
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessagePinned {
+    /// 频道 id
+    pub channel_id: String,
+    /// 操作者 id
+    pub operator_id: String,
+    /// 消息 id
+    pub msg_id: String,
+}
+
+/// 私聊消息的回应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrivateReactionEvent {
+    /// 消息 id
+    pub msg_id: String,
+    /// 回应的用户 id
+    pub user_id: String,
+    /// 私信会话 Code
+    pub chat_code: String,
+    /// 表情
+    pub emoji: ReactionEmoji,
+}
+
+/// 私聊消息更新
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrivateMessageUpdated {
+    /// 消息 id
+    pub msg_id: String,
+    /// 发送者 id
+    pub author_id: String,
+    /// 接收者 id
+    pub target_id: String,
+    /// 新的消息内容
+    #[serde(default)]
+    pub content: String,
+    /// 私信会话 Code
+    pub chat_code: String,
+    /// 更新时间 (毫秒时间戳)
+    #[serde(default)]
+    pub updated_at: i64,
+}
+
+/// 私聊消息删除
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrivateMessageDeleted {
+    /// 消息 id
+    pub msg_id: String,
+    /// 发送者 id
+    pub author_id: String,
+    /// 接收者 id
+    pub target_id: String,
+    /// 私信会话 Code
+    pub chat_code: String,
+    /// 删除时间 (毫秒时间戳)
+    #[serde(default)]
+    pub deleted_at: i64,
+}
+
+/// 用户加入语音频道
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelJoined {
+    /// 用户 id
+    pub user_id: String,
+    /// 频道 id
+    pub channel_id: String,
+    /// 加入时间 (毫秒时间戳)
+    #[serde(default)]
+    pub joined_at: i64,
+}
+
+/// 用户退出语音频道
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelExited {
+    /// 用户 id
+    pub user_id: String,
+    /// 频道 id
+    pub channel_id: String,
+    /// 退出时间 (毫秒时间戳)
+    #[serde(default)]
+    pub exited_at: i64,
+}
+
+/// 用户信息更新 (用户名或头像)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserUpdated {
+    /// 用户 id
+    pub user_id: String,
+    /// 用户名
+    #[serde(default)]
+    pub username: String,
+    /// 头像地址
+    #[serde(default)]
+    pub avatar: String,
+}
+
+/// 机器人加入/退出服务器
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SelfGuildEvent {
+    /// 服务器 id
+    pub guild_id: String,
+}
+
+/// 卡片消息中的按钮被点击
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageBtnClick {
+    /// 按钮的 value
+    pub value: String,
+    /// 卡片消息 id
+    pub msg_id: String,
+    /// 点击的用户 id
+    pub user_id: String,
+    /// 卡片所在的频道 id (私聊时为用户 id)
+    pub target_id: String,
+    /// 点击的用户信息
+    pub user_info: Option<User>,
+}
@@ -1,12 +1,25 @@
 pub mod api;
 pub mod client;
+pub mod event;
+pub mod middleware;
 pub mod models;
+pub mod permission;
+pub mod ratelimit;
+pub mod retry;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod utils;
 pub mod webhook;
 pub mod websocket;
 
 // 重新导出主要类型以便外部使用
-pub use client::{KookClient, PageParams};
+pub use api::{AssetUpload, VoiceSession};
+pub use client::{KookClient, KookClientBuilder, PageParams, PaginateOptions};
+pub use event::{Event, MessageEvent, MessageExtra, SystemEvent, SystemEventKind};
+pub use middleware::{Middleware, RequestContext, LoggingMiddleware, ReadOnlyMiddleware};
 pub use models::*;
-pub use webhook::{WebhookHandler, DefaultWebhookHandler, WebhookConfig, start_webhook_server};
+pub use permission::Permission;
+pub use ratelimit::{RateLimiter, RateLimitInfo};
+pub use retry::RetryPolicy;
+pub use webhook::{WebhookHandler, DefaultWebhookHandler, WebhookConfig, WebhookEvent, WebhookChallenge, start_webhook_server};
 pub use websocket::{KookWebSocketClient, EventHandler};
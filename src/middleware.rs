@@ -0,0 +1,103 @@
+//! REST 请求中间件，用于审计、注入请求头、统计和日志等
+use reqwest::Method;
+use serde_json::Value;
+use crate::models::KookError;
+
+/// 一次 REST 调用的请求信息，中间件可以在请求发送前修改它
+#[derive(Debug, Clone)]
+pub struct RequestContext {
+    /// 请求方法
+    pub method: Method,
+    /// 请求路径，例如 `/v3/message/create`
+    pub path: String,
+    /// 查询参数
+    pub query: Vec<(String, String)>,
+    /// JSON 请求体
+    ///
+    /// multipart 请求 (如 `upload_asset`) 的表单无法转换为 JSON，此处始终为 `None`，
+    /// 中间件只能通过 `method` 和 `path` 识别这类请求，修改该字段也不会影响发送的表单。
+    pub body: Option<Value>,
+    /// 附加的请求头
+    pub headers: Vec<(String, String)>,
+}
+
+impl RequestContext {
+    /// 创建请求信息
+    pub fn new(method: Method, path: &str, query: Option<&[(&str, &str)]>, body: Option<&Value>) -> Self {
+        Self {
+            method,
+            path: path.to_string(),
+            query: query
+                .unwrap_or_default()
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            body: body.cloned(),
+            headers: Vec::new(),
+        }
+    }
+
+    /// 是否为会修改数据的请求 (非 GET/HEAD)
+    pub fn is_mutating(&self) -> bool {
+        self.method != Method::GET && self.method != Method::HEAD
+    }
+
+    /// 添加一个请求头
+    pub fn insert_header(&mut self, name: &str, value: &str) {
+        self.headers.push((name.to_string(), value.to_string()));
+    }
+}
+
+/// 请求中间件
+///
+/// 中间件按照注册顺序调用 `before_request`，按照相反的顺序调用 `after_response`。
+/// 某个中间件提前返回结果时，排在它之后的中间件既不会收到 `before_request`，
+/// 也不会收到 `after_response`。
+/// 同一次调用中的所有重试对中间件来说是一次请求。
+pub trait Middleware: Send + Sync + 'static {
+    /// 请求发送前调用，可以修改请求信息
+    ///
+    /// 返回 `Some` 时不再发送请求，直接以该结果 (响应中的 `data` 或错误) 作为调用结果。
+    fn before_request(&self, ctx: &mut RequestContext) -> Option<Result<Value, KookError>> {
+        let _ = ctx;
+        None
+    }
+
+    /// 调用结束后调用，可以看到最终的响应数据或错误
+    fn after_response(&self, ctx: &RequestContext, result: &Result<Value, KookError>) {
+        let _ = (ctx, result);
+    }
+}
+
+/// 记录每次请求及其结果的日志中间件
+#[derive(Debug, Clone, Default)]
+pub struct LoggingMiddleware;
+
+impl Middleware for LoggingMiddleware {
+    fn before_request(&self, ctx: &mut RequestContext) -> Option<Result<Value, KookError>> {
+        log::debug!("--> {} {} query={:?} body={:?}", ctx.method, ctx.path, ctx.query, ctx.body);
+        None
+    }
+
+    fn after_response(&self, ctx: &RequestContext, result: &Result<Value, KookError>) {
+        match result {
+            Ok(_) => log::debug!("<-- {} {} OK", ctx.method, ctx.path),
+            Err(e) => log::warn!("<-- {} {} 失败: {}", ctx.method, ctx.path, e),
+        }
+    }
+}
+
+/// 阻止所有会修改数据的请求，适合只读或演练模式
+#[derive(Debug, Clone, Default)]
+pub struct ReadOnlyMiddleware;
+
+impl Middleware for ReadOnlyMiddleware {
+    fn before_request(&self, ctx: &mut RequestContext) -> Option<Result<Value, KookError>> {
+        if ctx.is_mutating() {
+            return Some(Err(KookError::Params(format!(
+                "只读模式下禁止调用 {} {}", ctx.method, ctx.path
+            ))));
+        }
+        None
+    }
+}
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use crate::permission::Permission;
 
 /// API 通用响应结构 (按照官方文档规范)
 #[derive(Debug, Serialize, Deserialize)]
@@ -36,21 +37,79 @@ pub struct PagedResponse<T> {
 }
 
 /// 用户信息
-#[derive(Debug, Serialize, Deserialize)]
+///
+/// KOOK 在不同接口中返回的字段不完全相同，缺失的字段使用默认值，
+/// 未定义的字段保存在 `other` 中。
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct User {
     pub id: String,
     pub username: String,
+    #[serde(default)]
     pub identify_num: String,
+    #[serde(default)]
     pub online: bool,
+    #[serde(default)]
     pub bot: bool,
+    #[serde(default)]
     pub status: i32,
+    #[serde(default)]
     pub avatar: String,
+    #[serde(default)]
     pub vip_avatar: Option<String>,
+    #[serde(default)]
     pub nickname: String,
+    #[serde(default)]
     pub roles: Vec<i32>,
+    #[serde(default)]
     pub is_vip: bool,
+    #[serde(default)]
     pub vip_amp: bool,
+    #[serde(default)]
     pub tag_info: Option<serde_json::Value>,
+    /// 个人资料背景图
+    #[serde(default)]
+    pub banner: Option<String>,
+    /// 是否为官方账号
+    #[serde(default)]
+    pub is_sys: Option<bool>,
+    /// 手机号是否已验证
+    #[serde(default)]
+    pub mobile_verified: Option<bool>,
+    /// 未在模型中定义的字段
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+impl User {
+    /// 是否拥有指定角色
+    pub fn has_role(&self, role_id: i32) -> bool {
+        self.roles.contains(&role_id)
+    }
+
+    /// 从服务器角色列表中找出该用户拥有的角色
+    pub fn roles_in<'a>(&self, roles: &'a [Role]) -> Vec<&'a Role> {
+        roles.iter().filter(|role| self.has_role(role.role_id)).collect()
+    }
+
+    /// 根据服务器角色列表计算该用户在服务器中的权限 (包括 @全体成员 角色的权限)
+    pub fn guild_permissions(&self, roles: &[Role]) -> Permission {
+        crate::permission::guild_permissions(roles, &self.roles)
+    }
+
+    /// 计算该用户在频道中的实际权限，参见 [`crate::permission::channel_permissions`]
+    pub fn channel_permissions(&self, roles: &[Role], channel: &Channel, parent: Option<&Channel>) -> Permission {
+        crate::permission::channel_permissions(roles, &self.id, &self.roles, channel, parent)
+    }
+}
+
+/// 用户的在线状态 (user/get-online-status)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OnlineStatus {
+    /// 是否在线
+    pub online: bool,
+    /// 在线的客户端类型，如 Websocket、Webhook
+    #[serde(default)]
+    pub online_os: Vec<String>,
 }
 
 /// 服务器信息
@@ -58,15 +117,27 @@ pub struct User {
 pub struct Guild {
     pub id: String,
     pub name: String,
+    #[serde(default)]
     pub topic: String,
+    #[serde(default)]
     pub user_id: String,
+    #[serde(default)]
     pub icon: String,
+    #[serde(default)]
     pub notify_type: i32,
+    #[serde(default)]
     pub region: String,
+    #[serde(default)]
     pub enable_open: bool,
+    #[serde(default, with = "int_or_str::string")]
     pub open_id: String,
+    #[serde(default)]
     pub default_channel_id: String,
+    #[serde(default)]
     pub welcome_channel_id: String,
+    /// 未在模型中定义的字段
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
 }
 
 /// 频道信息
@@ -74,18 +145,568 @@ pub struct Guild {
 pub struct Channel {
     pub id: String,
     pub name: String,
+    #[serde(default)]
     pub user_id: String,
+    #[serde(default)]
     pub guild_id: String,
+    #[serde(default)]
     pub topic: String,
+    #[serde(default)]
     pub is_category: bool,
+    #[serde(default)]
     pub parent_id: String,
+    #[serde(default)]
     pub level: i32,
+    #[serde(default)]
     pub slow_mode: i32,
+    #[serde(default)]
     pub r#type: i32,
-    pub permission_overwrites: Vec<serde_json::Value>,
-    pub permission_users: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub permission_overwrites: Vec<PermissionOverwrite>,
+    #[serde(default)]
+    pub permission_users: Vec<UserPermissionOverwrite>,
+    #[serde(default)]
     pub permission_sync: i32,
+    #[serde(default)]
     pub has_password: bool,
+    /// 未在模型中定义的字段
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Channel {
+    /// 是否与所属分组同步权限
+    pub fn is_permission_synced(&self) -> bool {
+        self.permission_sync == 1
+    }
+}
+
+/// 服务器详情 (guild/view)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GuildView {
+    /// 服务器基本信息
+    #[serde(flatten)]
+    pub guild: Guild,
+    /// 角色列表
+    #[serde(default)]
+    pub roles: Vec<Role>,
+    /// 频道列表 (仅包含当前用户可见的频道)
+    #[serde(default)]
+    pub channels: Vec<Channel>,
+    /// 服务器助力数量
+    #[serde(default)]
+    pub boost_num: i32,
+    /// 服务器等级
+    #[serde(default)]
+    pub level: i32,
+}
+
+/// 服务器角色
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Role {
+    /// 角色 id，@全体成员 角色的 id 为 0
+    pub role_id: i32,
+    /// 角色名称
+    pub name: String,
+    /// 颜色
+    #[serde(default)]
+    pub color: i32,
+    /// 顺序位置，值越小越靠前
+    #[serde(default)]
+    pub position: i32,
+    /// 是否在成员列表中单独显示
+    #[serde(default, with = "int_bool")]
+    pub hoist: bool,
+    /// 是否允许任何人 @提及此角色
+    #[serde(default, with = "int_bool")]
+    pub mentionable: bool,
+    /// 权限
+    #[serde(default)]
+    pub permissions: Permission,
+}
+
+impl Role {
+    /// 是否为 @全体成员 角色
+    pub fn is_everyone(&self) -> bool {
+        self.role_id == 0
+    }
+}
+
+/// 修改服务器角色的请求，未设置的字段保持不变
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateRole {
+    /// 服务器 id
+    pub guild_id: String,
+    /// 角色 id
+    pub role_id: i32,
+    /// 角色名称
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// 颜色
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<i32>,
+    /// 是否在成员列表中单独显示
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "int_bool::option::serialize")]
+    pub hoist: Option<bool>,
+    /// 是否允许任何人 @提及此角色
+    #[serde(skip_serializing_if = "Option::is_none", serialize_with = "int_bool::option::serialize")]
+    pub mentionable: Option<bool>,
+    /// 权限
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Permission>,
+}
+
+impl UpdateRole {
+    /// 修改指定服务器中的角色
+    pub fn new(guild_id: &str, role_id: i32) -> Self {
+        Self {
+            guild_id: guild_id.to_string(),
+            role_id,
+            ..Default::default()
+        }
+    }
+
+    /// 设置角色名称
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// 设置颜色
+    pub fn color(mut self, color: i32) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// 设置是否在成员列表中单独显示
+    pub fn hoist(mut self, hoist: bool) -> Self {
+        self.hoist = Some(hoist);
+        self
+    }
+
+    /// 设置是否允许任何人 @提及此角色
+    pub fn mentionable(mut self, mentionable: bool) -> Self {
+        self.mentionable = Some(mentionable);
+        self
+    }
+
+    /// 设置权限
+    pub fn permissions(mut self, permissions: Permission) -> Self {
+        self.permissions = Some(permissions);
+        self
+    }
+}
+
+/// 赋予或删除用户角色的结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoleGrant {
+    /// 用户 id
+    pub user_id: String,
+    /// 服务器 id
+    pub guild_id: String,
+    /// 用户当前拥有的角色 id
+    pub roles: Vec<i32>,
+}
+
+/// 以 0/1 表示的布尔值
+mod int_bool {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Raw {
+        Bool(bool),
+        Int(i64),
+    }
+
+    pub fn serialize<S: Serializer>(value: &bool, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(*value as u8)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+        Ok(match Raw::deserialize(deserializer)? {
+            Raw::Bool(value) => value,
+            Raw::Int(value) => value != 0,
+        })
+    }
+
+    pub mod option {
+        use serde::Serializer;
+
+        pub fn serialize<S: Serializer>(value: &Option<bool>, serializer: S) -> Result<S::Ok, S::Error> {
+            match value {
+                Some(value) => super::serialize(value, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+    }
+}
+
+/// 服务器成员 (guild/user-list，以及指定服务器的 user/view)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GuildMember {
+    /// 用户信息
+    #[serde(flatten)]
+    pub user: User,
+    /// 加入服务器的时间 (毫秒时间戳)
+    #[serde(default)]
+    pub joined_at: i64,
+    /// 最后活跃时间 (毫秒时间戳)
+    #[serde(default)]
+    pub active_time: i64,
+}
+
+/// 服务器成员列表 (guild/user-list)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GuildUserList {
+    /// 成员列表
+    #[serde(default)]
+    pub items: Vec<GuildMember>,
+    /// 分页信息
+    pub meta: Meta,
+    /// 排序信息
+    pub sort: Option<serde_json::Value>,
+    /// 用户数量
+    #[serde(default)]
+    pub user_count: i32,
+    /// 在线用户数量
+    #[serde(default)]
+    pub online_count: i32,
+    /// 离线用户数量
+    #[serde(default)]
+    pub offline_count: i32,
+}
+
+/// 排序方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    /// 顺序
+    Asc,
+    /// 倒序
+    Desc,
+}
+
+impl SortOrder {
+    /// 接口参数中的取值
+    pub fn as_param(&self) -> &'static str {
+        match self {
+            SortOrder::Asc => "0",
+            SortOrder::Desc => "1",
+        }
+    }
+}
+
+/// 服务器成员列表的筛选条件
+#[derive(Debug, Clone, Default)]
+pub struct GuildUserQuery {
+    /// 只返回该频道中的用户
+    pub channel_id: Option<String>,
+    /// 按用户名或昵称搜索
+    pub search: Option<String>,
+    /// 按角色 id 筛选
+    pub role_id: Option<i32>,
+    /// 只返回手机号已验证 (true) 或未验证 (false) 的用户
+    pub mobile_verified: Option<bool>,
+    /// 按活跃时间排序
+    pub active_time: Option<SortOrder>,
+    /// 按加入时间排序
+    pub joined_at: Option<SortOrder>,
+    /// 只返回指定 id 的用户
+    pub filter_user_id: Option<String>,
+}
+
+impl GuildUserQuery {
+    /// 转换为查询参数
+    pub fn to_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(channel_id) = &self.channel_id {
+            query.push(("channel_id", channel_id.clone()));
+        }
+        if let Some(search) = &self.search {
+            query.push(("search", search.clone()));
+        }
+        if let Some(role_id) = self.role_id {
+            query.push(("role_id", role_id.to_string()));
+        }
+        if let Some(verified) = self.mobile_verified {
+            query.push(("mobile_verified", if verified { "1" } else { "0" }.to_string()));
+        }
+        if let Some(order) = self.active_time {
+            query.push(("active_time", order.as_param().to_string()));
+        }
+        if let Some(order) = self.joined_at {
+            query.push(("joined_at", order.as_param().to_string()));
+        }
+        if let Some(user_id) = &self.filter_user_id {
+            query.push(("filter_user_id", user_id.clone()));
+        }
+        query
+    }
+}
+
+/// 服务器静音/闭麦类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MuteType {
+    /// 麦克风静音 (闭麦)
+    Mic = 1,
+    /// 耳机静音
+    Headset = 2,
+}
+
+/// 某一类静音的用户列表
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MuteList {
+    /// 静音类型
+    pub r#type: i32,
+    /// 用户 id 列表
+    pub user_ids: Vec<String>,
+}
+
+/// 服务器静音/闭麦列表 (guild-mute/list)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GuildMuteList {
+    /// 闭麦的用户
+    pub mic: MuteList,
+    /// 耳机静音的用户
+    pub headset: MuteList,
+}
+
+/// 服务器黑名单中的用户
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlacklistEntry {
+    /// 用户 id
+    pub user_id: String,
+    /// 加入黑名单的时间 (毫秒时间戳)
+    #[serde(default)]
+    pub created_time: i64,
+    /// 加入黑名单的原因
+    #[serde(default)]
+    pub remark: String,
+    /// 用户信息
+    pub user: Option<User>,
+}
+
+/// 服务器助力记录
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GuildBoost {
+    /// 服务器 id
+    pub guild_id: String,
+    /// 用户 id
+    pub user_id: String,
+    /// 助力开始时间 (秒级时间戳)
+    pub start_time: i64,
+    /// 助力结束时间 (秒级时间戳)
+    pub end_time: i64,
+    /// 助力用户
+    pub user: User,
+}
+
+/// 频道类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChannelType {
+    /// 文字频道
+    Text = 1,
+    /// 语音频道
+    Voice = 2,
+}
+
+/// 语音频道音质
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VoiceQuality {
+    /// 流畅
+    #[serde(rename = "1")]
+    Low,
+    /// 正常
+    #[serde(rename = "2")]
+    Normal,
+    /// 高质量
+    #[serde(rename = "3")]
+    High,
+}
+
+/// 创建频道的请求
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateChannel {
+    /// 服务器 id
+    pub guild_id: String,
+    /// 频道名称
+    pub name: String,
+    /// 父分组 id
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    /// 频道类型，1 文字，2 语音
+    #[serde(rename = "type")]
+    pub channel_type: i32,
+    /// 语音频道人数限制
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit_amount: Option<i32>,
+    /// 语音音质
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice_quality: Option<VoiceQuality>,
+    /// 是否为分组，1 是，0 否
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_category: Option<i32>,
+}
+
+impl CreateChannel {
+    /// 创建文字频道
+    pub fn text(guild_id: &str, name: &str) -> Self {
+        Self {
+            guild_id: guild_id.to_string(),
+            name: name.to_string(),
+            parent_id: None,
+            channel_type: ChannelType::Text as i32,
+            limit_amount: None,
+            voice_quality: None,
+            is_category: None,
+        }
+    }
+
+    /// 创建语音频道
+    pub fn voice(guild_id: &str, name: &str) -> Self {
+        Self {
+            channel_type: ChannelType::Voice as i32,
+            ..Self::text(guild_id, name)
+        }
+    }
+
+    /// 创建频道分组
+    pub fn category(guild_id: &str, name: &str) -> Self {
+        Self {
+            is_category: Some(1),
+            ..Self::text(guild_id, name)
+        }
+    }
+
+    /// 设置父分组
+    pub fn parent_id(mut self, parent_id: &str) -> Self {
+        self.parent_id = Some(parent_id.to_string());
+        self
+    }
+
+    /// 设置语音频道人数限制
+    pub fn limit_amount(mut self, limit: i32) -> Self {
+        self.limit_amount = Some(limit);
+        self
+    }
+
+    /// 设置语音音质
+    pub fn voice_quality(mut self, quality: VoiceQuality) -> Self {
+        self.voice_quality = Some(quality);
+        self
+    }
+}
+
+/// 编辑频道的请求，未设置的字段保持不变
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateChannel {
+    /// 频道名称
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// 频道排序
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level: Option<i32>,
+    /// 父分组 id
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    /// 频道简介 (文字频道)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub topic: Option<String>,
+    /// 慢速模式，单位毫秒 (文字频道)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slow_mode: Option<i32>,
+    /// 人数限制 (语音频道)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit_amount: Option<i32>,
+    /// 音质 (语音频道)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice_quality: Option<VoiceQuality>,
+    /// 密码 (语音频道)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+}
+
+/// 频道权限设置的对象
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChannelRoleTarget {
+    /// 角色
+    Role(i32),
+    /// 用户
+    User(String),
+}
+
+impl ChannelRoleTarget {
+    /// 接口参数中的 type 字段
+    pub fn type_param(&self) -> &'static str {
+        match self {
+            ChannelRoleTarget::Role(_) => "role_id",
+            ChannelRoleTarget::User(_) => "user_id",
+        }
+    }
+
+    /// 接口参数中的 value 字段
+    pub fn value_param(&self) -> String {
+        match self {
+            ChannelRoleTarget::Role(role_id) => role_id.to_string(),
+            ChannelRoleTarget::User(user_id) => user_id.clone(),
+        }
+    }
+}
+
+/// 针对角色的频道权限覆写
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PermissionOverwrite {
+    /// 角色 id
+    pub role_id: i32,
+    /// 允许的权限
+    #[serde(default)]
+    pub allow: Permission,
+    /// 禁止的权限
+    #[serde(default)]
+    pub deny: Permission,
+}
+
+/// 针对用户的频道权限覆写
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserPermissionOverwrite {
+    /// 用户信息
+    #[serde(default)]
+    pub user: User,
+    /// 允许的权限
+    #[serde(default)]
+    pub allow: Permission,
+    /// 禁止的权限
+    #[serde(default)]
+    pub deny: Permission,
+}
+
+/// 频道的角色权限详情 (channel-role/index)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelRoleIndex {
+    /// 针对角色的权限覆写
+    #[serde(default)]
+    pub permission_overwrites: Vec<PermissionOverwrite>,
+    /// 针对用户的权限覆写
+    #[serde(default)]
+    pub permission_users: Vec<UserPermissionOverwrite>,
+    /// 是否与分组权限同步，1 同步
+    #[serde(default)]
+    pub permission_sync: i32,
+}
+
+/// 创建或更新频道权限后的结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelRole {
+    /// 角色 id (针对角色时)
+    pub role_id: Option<i32>,
+    /// 用户 id (针对用户时)
+    pub user_id: Option<String>,
+    /// 允许的权限
+    #[serde(default)]
+    pub allow: Permission,
+    /// 禁止的权限
+    #[serde(default)]
+    pub deny: Permission,
 }
 
 /// Gateway 响应
@@ -94,6 +715,1137 @@ pub struct Gateway {
     pub url: String,
 }
 
+/// 消息类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum MessageType {
+    /// 文字消息
+    #[default]
+    Text,
+    /// 图片消息
+    Image,
+    /// 视频消息
+    Video,
+    /// 文件消息
+    File,
+    /// 音频消息
+    Audio,
+    /// KMarkdown 消息
+    KMarkdown,
+    /// 卡片消息
+    Card,
+    /// 系统消息
+    System,
+    /// 未知的消息类型
+    Other(i32),
+}
+
+impl From<i32> for MessageType {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => MessageType::Text,
+            2 => MessageType::Image,
+            3 => MessageType::Video,
+            4 => MessageType::File,
+            8 => MessageType::Audio,
+            9 => MessageType::KMarkdown,
+            10 => MessageType::Card,
+            255 => MessageType::System,
+            other => MessageType::Other(other),
+        }
+    }
+}
+
+impl From<MessageType> for i32 {
+    fn from(value: MessageType) -> Self {
+        match value {
+            MessageType::Text => 1,
+            MessageType::Image => 2,
+            MessageType::Video => 3,
+            MessageType::File => 4,
+            MessageType::Audio => 8,
+            MessageType::KMarkdown => 9,
+            MessageType::Card => 10,
+            MessageType::System => 255,
+            MessageType::Other(other) => other,
+        }
+    }
+}
+
+/// 发送频道消息的请求
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateMessage {
+    /// 消息类型
+    #[serde(rename = "type")]
+    pub message_type: MessageType,
+    /// 目标频道 id
+    pub target_id: String,
+    /// 消息内容
+    pub content: String,
+    /// 回复某条消息的 msgId
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote: Option<String>,
+    /// 服务端不做处理，原样返回
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<String>,
+    /// 用户 id，设置后消息为临时消息，只有该用户可见
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temp_target_id: Option<String>,
+    /// 模板消息 id，设置后 content 为模板渲染所需的数据
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_id: Option<String>,
+}
+
+impl CreateMessage {
+    /// 创建一条发往指定频道的文字消息
+    pub fn new(target_id: &str, content: &str) -> Self {
+        Self {
+            target_id: target_id.to_string(),
+            content: content.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// 设置消息类型
+    pub fn message_type(mut self, message_type: MessageType) -> Self {
+        self.message_type = message_type;
+        self
+    }
+
+    /// 回复某条消息
+    pub fn quote(mut self, msg_id: &str) -> Self {
+        self.quote = Some(msg_id.to_string());
+        self
+    }
+
+    /// 设置 nonce
+    pub fn nonce(mut self, nonce: &str) -> Self {
+        self.nonce = Some(nonce.to_string());
+        self
+    }
+
+    /// 设置为只有指定用户可见的临时消息
+    pub fn temp_target_id(mut self, user_id: &str) -> Self {
+        self.temp_target_id = Some(user_id.to_string());
+        self
+    }
+
+    /// 使用消息模板发送
+    pub fn template_id(mut self, template_id: &str) -> Self {
+        self.template_id = Some(template_id.to_string());
+        self
+    }
+
+    /// 使用消息模板发送，`data` 为模板渲染所需的数据
+    pub fn from_template(target_id: &str, template: &MessageTemplate, data: &serde_json::Value) -> Self {
+        Self::new(target_id, &data.to_string())
+            .message_type(template.msgtype.message_type())
+            .template_id(&template.id)
+    }
+}
+
+/// 发送消息的结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageCreated {
+    /// 服务端生成的消息 id
+    pub msg_id: String,
+    /// 消息发送时间 (服务器时间戳)
+    pub msg_timestamp: i64,
+    /// 请求中的 nonce
+    #[serde(default)]
+    pub nonce: String,
+}
+
+/// 编辑消息的请求
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateMessage {
+    /// 消息 id
+    pub msg_id: String,
+    /// 新的消息内容
+    pub content: String,
+    /// 回复某条消息的 msgId，为空字符串时取消回复
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote: Option<String>,
+    /// 临时消息的目标用户 id，只能编辑临时消息时使用
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temp_target_id: Option<String>,
+    /// 模板消息 id
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_id: Option<String>,
+}
+
+impl UpdateMessage {
+    /// 将消息内容修改为 `content`
+    pub fn new(msg_id: &str, content: &str) -> Self {
+        Self {
+            msg_id: msg_id.to_string(),
+            content: content.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+/// 查询消息列表时相对于参考消息的方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageListFlag {
+    /// 参考消息之前的消息
+    Before,
+    /// 参考消息前后的消息
+    Around,
+    /// 参考消息之后的消息
+    After,
+}
+
+impl MessageListFlag {
+    /// 接口参数中的取值
+    pub fn as_param(&self) -> &'static str {
+        match self {
+            MessageListFlag::Before => "before",
+            MessageListFlag::Around => "around",
+            MessageListFlag::After => "after",
+        }
+    }
+}
+
+/// 消息列表的查询条件
+#[derive(Debug, Clone, Default)]
+pub struct MessageListQuery {
+    /// 参考消息 id，为空时查询最新的消息
+    pub msg_id: Option<String>,
+    /// 只查询置顶消息
+    pub pin: Option<bool>,
+    /// 相对于参考消息的方向
+    pub flag: Option<MessageListFlag>,
+    /// 每次返回的消息数量，默认 50
+    pub page_size: Option<i32>,
+}
+
+impl MessageListQuery {
+    /// 转换为查询参数
+    pub fn to_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(msg_id) = &self.msg_id {
+            query.push(("msg_id", msg_id.clone()));
+        }
+        if let Some(pin) = self.pin {
+            query.push(("pin", if pin { "1" } else { "0" }.to_string()));
+        }
+        if let Some(flag) = self.flag {
+            query.push(("flag", flag.as_param().to_string()));
+        }
+        if let Some(page_size) = self.page_size {
+            query.push(("page_size", page_size.to_string()));
+        }
+        query
+    }
+}
+
+/// 消息附件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attachment {
+    /// 附件类型，如 image、file、video、audio
+    pub r#type: String,
+    /// 附件地址
+    pub url: String,
+    /// 附件名称
+    #[serde(default)]
+    pub name: String,
+    /// 文件 MIME 类型
+    pub file_type: Option<String>,
+    /// 文件大小 (字节)
+    pub size: Option<i64>,
+    /// 音视频时长 (秒)
+    pub duration: Option<f64>,
+    /// 图片/视频宽度
+    pub width: Option<i32>,
+    /// 图片/视频高度
+    pub height: Option<i32>,
+}
+
+/// 回应使用的表情
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReactionEmoji {
+    /// 表情 id
+    pub id: String,
+    /// 表情名称
+    #[serde(default)]
+    pub name: String,
+}
+
+/// 消息上的回应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Reaction {
+    /// 表情
+    pub emoji: ReactionEmoji,
+    /// 回应的数量
+    pub count: i32,
+    /// 当前用户是否回应过
+    pub me: bool,
+}
+
+/// 回应了某个表情的用户
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReactionUser {
+    /// 用户信息
+    #[serde(flatten)]
+    pub user: User,
+    /// 回应的时间 (毫秒时间戳)
+    #[serde(default)]
+    pub reaction_time: i64,
+}
+
+/// 被引用的消息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Quote {
+    /// 消息 id
+    pub id: String,
+    /// 消息类型
+    pub r#type: MessageType,
+    /// 消息内容
+    pub content: String,
+    /// 创建时间 (毫秒时间戳)
+    pub create_at: i64,
+    /// 消息作者
+    pub author: User,
+}
+
+/// 频道消息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Message {
+    /// 消息 id
+    pub id: String,
+    /// 消息类型
+    pub r#type: MessageType,
+    /// 消息作者
+    pub author: User,
+    /// 消息内容
+    pub content: String,
+    /// 提及的用户 id
+    #[serde(default)]
+    pub mention: Vec<String>,
+    /// 是否提及全体成员
+    #[serde(default)]
+    pub mention_all: bool,
+    /// 提及的角色 id
+    #[serde(default)]
+    pub mention_roles: Vec<i32>,
+    /// 是否提及在线成员
+    #[serde(default)]
+    pub mention_here: bool,
+    /// 超链接解析数据
+    #[serde(default)]
+    pub embeds: Vec<serde_json::Value>,
+    /// 附件
+    pub attachments: Option<Attachment>,
+    /// 创建时间 (毫秒时间戳)
+    pub create_at: i64,
+    /// 最后修改时间 (毫秒时间戳)
+    #[serde(default)]
+    pub updated_at: i64,
+    /// 回应列表
+    #[serde(default)]
+    pub reactions: Vec<Reaction>,
+    /// 引用的消息
+    pub quote: Option<Box<Quote>>,
+    /// 频道 id (message/view 返回)
+    pub channel_id: Option<String>,
+}
+
+/// 消息列表 (message/list)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MessageList {
+    /// 消息列表
+    pub items: Vec<Message>,
+}
+
+/// 私聊会话的对方用户
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatTargetInfo {
+    /// 用户 id
+    pub id: String,
+    /// 用户名
+    pub username: String,
+    /// 是否在线
+    #[serde(default)]
+    pub online: bool,
+    /// 头像地址
+    #[serde(default)]
+    pub avatar: String,
+}
+
+/// 私聊会话
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserChat {
+    /// 私信会话 Code
+    pub code: String,
+    /// 上次阅读消息的时间 (毫秒时间戳)
+    #[serde(default)]
+    pub last_read_time: i64,
+    /// 最新消息时间 (毫秒时间戳)
+    #[serde(default)]
+    pub latest_msg_time: i64,
+    /// 未读消息数
+    #[serde(default)]
+    pub unread_count: i32,
+    /// 是否为好友
+    #[serde(default)]
+    pub is_friend: bool,
+    /// 是否已屏蔽对方
+    #[serde(default)]
+    pub is_blocked: bool,
+    /// 是否被对方屏蔽
+    #[serde(default)]
+    pub is_target_blocked: bool,
+    /// 对方用户
+    pub target_info: ChatTargetInfo,
+}
+
+/// 私信的发送对象，可以是用户 id 或私信会话 Code
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DirectMessageTarget {
+    /// 目标用户 id
+    User(String),
+    /// 私信会话 Code
+    Chat(String),
+}
+
+impl DirectMessageTarget {
+    /// 接口参数中的字段名和取值
+    pub fn as_param(&self) -> (&'static str, &str) {
+        match self {
+            DirectMessageTarget::User(user_id) => ("target_id", user_id),
+            DirectMessageTarget::Chat(chat_code) => ("chat_code", chat_code),
+        }
+    }
+}
+
+/// 发送私信的请求
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateDirectMessage {
+    /// 消息类型
+    #[serde(rename = "type")]
+    pub message_type: MessageType,
+    /// 目标用户 id，与 `chat_code` 二选一
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_id: Option<String>,
+    /// 私信会话 Code，与 `target_id` 二选一
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_code: Option<String>,
+    /// 消息内容
+    pub content: String,
+    /// 回复某条消息的 msgId
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote: Option<String>,
+    /// 服务端不做处理，原样返回
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<String>,
+    /// 模板消息 id，设置后 content 为模板渲染所需的数据
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_id: Option<String>,
+}
+
+impl CreateDirectMessage {
+    /// 创建一条发给指定对象的文字私信
+    pub fn new(target: &DirectMessageTarget, content: &str) -> Self {
+        let mut message = Self {
+            content: content.to_string(),
+            ..Default::default()
+        };
+        match target {
+            DirectMessageTarget::User(user_id) => message.target_id = Some(user_id.clone()),
+            DirectMessageTarget::Chat(chat_code) => message.chat_code = Some(chat_code.clone()),
+        }
+        message
+    }
+
+    /// 设置消息类型
+    pub fn message_type(mut self, message_type: MessageType) -> Self {
+        self.message_type = message_type;
+        self
+    }
+
+    /// 回复某条消息
+    pub fn quote(mut self, msg_id: &str) -> Self {
+        self.quote = Some(msg_id.to_string());
+        self
+    }
+
+    /// 设置 nonce
+    pub fn nonce(mut self, nonce: &str) -> Self {
+        self.nonce = Some(nonce.to_string());
+        self
+    }
+
+    /// 使用消息模板发送
+    pub fn template_id(mut self, template_id: &str) -> Self {
+        self.template_id = Some(template_id.to_string());
+        self
+    }
+}
+
+/// 私信消息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DirectMessage {
+    /// 消息 id
+    pub id: String,
+    /// 消息类型
+    pub r#type: MessageType,
+    /// 作者的用户 id
+    pub author_id: String,
+    /// 消息内容
+    pub content: String,
+    /// 超链接解析数据
+    #[serde(default)]
+    pub embeds: Vec<serde_json::Value>,
+    /// 附件
+    pub attachments: Option<Attachment>,
+    /// 创建时间 (毫秒时间戳)
+    pub create_at: i64,
+    /// 最后修改时间 (毫秒时间戳)
+    #[serde(default)]
+    pub updated_at: i64,
+    /// 回应列表
+    #[serde(default)]
+    pub reactions: Vec<Reaction>,
+    /// 图片名称
+    #[serde(default)]
+    pub image_name: String,
+    /// 是否已读
+    #[serde(default)]
+    pub read_status: bool,
+    /// 引用的消息
+    pub quote: Option<Box<Quote>>,
+    /// 提及信息
+    pub mention_info: Option<serde_json::Value>,
+}
+
+/// 私信列表 (direct-message/list)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DirectMessageList {
+    /// 消息列表
+    pub items: Vec<DirectMessage>,
+}
+
+/// 服务器表情
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildEmoji {
+    /// 表情 id，格式为 `服务器id/随机字符串`
+    pub id: String,
+    /// 表情名称
+    pub name: String,
+    /// 上传者信息
+    pub user_info: Option<serde_json::Value>,
+}
+
+impl GuildEmoji {
+    /// KMarkdown 中的表情语法，例如 `(emj)name(emj)[id]`
+    pub fn to_kmarkdown(&self) -> String {
+        format!("(emj){}(emj)[{}]", self.name, self.id)
+    }
+
+    /// 添加、删除消息回应时使用的 emoji 参数
+    pub fn as_reaction(&self) -> &str {
+        &self.id
+    }
+}
+
+/// 邀请链接的对象
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InviteTarget {
+    /// 服务器 id
+    Guild(String),
+    /// 频道 id
+    Channel(String),
+}
+
+impl InviteTarget {
+    /// 接口参数中的字段名和取值
+    pub fn as_param(&self) -> (&'static str, &str) {
+        match self {
+            InviteTarget::Guild(guild_id) => ("guild_id", guild_id),
+            InviteTarget::Channel(channel_id) => ("channel_id", channel_id),
+        }
+    }
+}
+
+/// 邀请链接的有效时长
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InviteDuration {
+    /// 永久有效
+    Never = 0,
+    /// 30 分钟
+    HalfHour = 1800,
+    /// 1 小时
+    OneHour = 3600,
+    /// 6 小时
+    SixHours = 21600,
+    /// 12 小时
+    TwelveHours = 43200,
+    /// 1 天
+    #[default]
+    OneDay = 86400,
+    /// 7 天
+    SevenDays = 604800,
+}
+
+/// 邀请链接的可用次数
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InviteUses {
+    /// 不限次数
+    #[default]
+    Unlimited = -1,
+    /// 1 次
+    Once = 1,
+    /// 5 次
+    Five = 5,
+    /// 10 次
+    Ten = 10,
+    /// 25 次
+    TwentyFive = 25,
+    /// 50 次
+    Fifty = 50,
+    /// 100 次
+    Hundred = 100,
+}
+
+/// 邀请链接
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Invite {
+    /// 邀请链接 id
+    #[serde(default)]
+    pub id: i64,
+    /// 服务器 id
+    #[serde(default)]
+    pub guild_id: String,
+    /// 频道 id，服务器邀请时为空
+    #[serde(default)]
+    pub channel_id: String,
+    /// 邀请码
+    pub url_code: String,
+    /// 邀请链接地址
+    pub url: String,
+    /// 创建者
+    pub user: Option<User>,
+    /// 过期时间 (秒级时间戳)，0 表示永久有效
+    #[serde(default)]
+    pub expire_time: i64,
+    /// 有效时长 (秒)，0 表示永久有效
+    #[serde(default)]
+    pub duration: i64,
+    /// 设置的可用次数，-1 表示不限次数
+    #[serde(default)]
+    pub setting_times: i32,
+    /// 剩余可用次数
+    #[serde(default)]
+    pub remaining_times: i32,
+    /// 已使用次数
+    #[serde(default)]
+    pub using_times: i32,
+}
+
+/// 创建邀请链接的结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InviteCreated {
+    /// 邀请链接地址
+    pub url: String,
+}
+
+impl InviteCreated {
+    /// 邀请链接中的邀请码
+    pub fn url_code(&self) -> &str {
+        self.url.trim_end_matches('/').rsplit('/').next().unwrap_or_default()
+    }
+}
+
+/// 游戏列表的筛选类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GameListType {
+    /// 全部游戏
+    #[default]
+    All = 0,
+    /// 用户创建的游戏
+    Created = 1,
+    /// 系统内置的游戏
+    System = 2,
+}
+
+/// 游戏
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Game {
+    /// 游戏 id
+    pub id: i64,
+    /// 游戏名称
+    pub name: String,
+    /// 类型：0 游戏，1 VOICE，2 PROCESS
+    #[serde(default)]
+    pub r#type: i32,
+    /// 进程额外信息
+    #[serde(default)]
+    pub options: String,
+    /// 是否需要管理员权限
+    #[serde(default)]
+    pub kmhook_admin: bool,
+    /// 进程名
+    #[serde(default)]
+    pub process_name: Vec<String>,
+    /// 产品名
+    #[serde(default)]
+    pub product_name: Vec<String>,
+    /// 图标地址
+    #[serde(default)]
+    pub icon: String,
+}
+
+/// 听歌时使用的软件
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MusicSoftware {
+    /// 网易云音乐
+    #[default]
+    CloudMusic,
+    /// QQ 音乐
+    QQMusic,
+    /// 酷狗音乐
+    Kugou,
+}
+
+impl MusicSoftware {
+    /// 接口参数中的取值
+    pub fn as_param(&self) -> &'static str {
+        match self {
+            MusicSoftware::CloudMusic => "cloudmusic",
+            MusicSoftware::QQMusic => "qqmusic",
+            MusicSoftware::Kugou => "kugou",
+        }
+    }
+}
+
+/// 动态类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActivityType {
+    /// 正在玩
+    Game = 1,
+    /// 正在听
+    Music = 2,
+}
+
+/// 机器人的动态
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Activity {
+    /// 正在玩某个游戏
+    Playing {
+        /// 游戏 id
+        game_id: i64,
+    },
+    /// 正在听某首歌
+    Listening {
+        /// 使用的软件
+        software: MusicSoftware,
+        /// 歌手
+        singer: String,
+        /// 歌曲名称
+        music_name: String,
+    },
+}
+
+impl Activity {
+    /// 动态类型
+    pub fn activity_type(&self) -> ActivityType {
+        match self {
+            Activity::Playing { .. } => ActivityType::Game,
+            Activity::Listening { .. } => ActivityType::Music,
+        }
+    }
+}
+
+/// 亲密度上的图片
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntimacyImage {
+    /// 图片 id
+    pub id: String,
+    /// 图片地址
+    pub url: String,
+}
+
+/// 用户与机器人的亲密度 (intimacy/index)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Intimacy {
+    /// 机器人给用户显示的形象图片地址
+    #[serde(default)]
+    pub img_url: String,
+    /// 机器人显示给用户的社交信息
+    #[serde(default)]
+    pub social_info: String,
+    /// 用户上次查看的时间 (秒级时间戳)
+    #[serde(default)]
+    pub last_read: i64,
+    /// 上次修改的时间 (秒级时间戳)
+    #[serde(default)]
+    pub last_modify: i64,
+    /// 亲密度，0-2200
+    #[serde(default)]
+    pub score: i32,
+    /// 可选的形象图片
+    #[serde(default)]
+    pub img_list: Vec<IntimacyImage>,
+}
+
+/// 更新亲密度的请求，未设置的字段保持不变
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateIntimacy {
+    /// 用户 id
+    pub user_id: String,
+    /// 亲密度，0-2200
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<i32>,
+    /// 机器人显示给用户的社交信息
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub social_info: Option<String>,
+    /// 形象图片 id，需要在 `img_list` 中
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub img_id: Option<String>,
+}
+
+impl UpdateIntimacy {
+    /// 更新与指定用户的亲密度
+    pub fn new(user_id: &str) -> Self {
+        Self {
+            user_id: user_id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// 设置亲密度
+    pub fn score(mut self, score: i32) -> Self {
+        self.score = Some(score);
+        self
+    }
+
+    /// 设置社交信息
+    pub fn social_info(mut self, social_info: &str) -> Self {
+        self.social_info = Some(social_info.to_string());
+        self
+    }
+
+    /// 设置形象图片
+    pub fn img_id(mut self, img_id: &str) -> Self {
+        self.img_id = Some(img_id.to_string());
+        self
+    }
+}
+
+/// 服务器徽章的样式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BadgeStyle {
+    /// 服务器名称
+    #[default]
+    Name = 0,
+    /// 在线人数
+    Online = 1,
+    /// 在线人数和总人数
+    OnlineAndTotal = 2,
+}
+
+/// 消息模板的消息类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(from = "i32", into = "i32")]
+pub enum TemplateMessageType {
+    /// KMarkdown 消息
+    #[default]
+    KMarkdown,
+    /// 卡片消息
+    Card,
+    /// 其他类型
+    Other(i32),
+}
+
+impl From<i32> for TemplateMessageType {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => TemplateMessageType::KMarkdown,
+            2 => TemplateMessageType::Card,
+            other => TemplateMessageType::Other(other),
+        }
+    }
+}
+
+impl From<TemplateMessageType> for i32 {
+    fn from(value: TemplateMessageType) -> Self {
+        match value {
+            TemplateMessageType::KMarkdown => 1,
+            TemplateMessageType::Card => 2,
+            TemplateMessageType::Other(other) => other,
+        }
+    }
+}
+
+impl TemplateMessageType {
+    /// 使用该模板发送消息时的消息类型
+    pub fn message_type(&self) -> MessageType {
+        match self {
+            TemplateMessageType::Card => MessageType::Card,
+            _ => MessageType::KMarkdown,
+        }
+    }
+}
+
+/// 服务端消息模板
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageTemplate {
+    /// 模板 id
+    pub id: String,
+    /// 模板名称
+    pub title: String,
+    /// 模板语法类型，0 为 Twig
+    #[serde(default)]
+    pub r#type: i32,
+    /// 消息类型
+    #[serde(default)]
+    pub msgtype: TemplateMessageType,
+    /// 审核状态：0 审核中，1 审核通过，2 审核拒绝
+    #[serde(default)]
+    pub status: i32,
+    /// 测试数据 (JSON 字符串)
+    #[serde(default)]
+    pub test_data: String,
+    /// 测试频道 id
+    #[serde(default)]
+    pub test_channel: String,
+    /// 模板内容
+    #[serde(default)]
+    pub content: String,
+}
+
+/// 创建或修改消息模板的请求
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SaveTemplate {
+    /// 模板 id，修改时使用
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// 模板名称
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// 模板语法类型，0 为 Twig
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<i32>,
+    /// 消息类型
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub msgtype: Option<TemplateMessageType>,
+    /// 测试数据 (JSON 字符串)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test_data: Option<String>,
+    /// 测试频道 id
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test_channel: Option<String>,
+    /// 模板内容
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+}
+
+impl SaveTemplate {
+    /// 新建一个模板
+    pub fn new(title: &str, msgtype: TemplateMessageType, content: &str) -> Self {
+        Self {
+            title: Some(title.to_string()),
+            msgtype: Some(msgtype),
+            content: Some(content.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// 修改已有的模板，只提交设置过的字段
+    pub fn update(id: &str) -> Self {
+        Self {
+            id: Some(id.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// 设置模板名称
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    /// 设置模板内容
+    pub fn content(mut self, content: &str) -> Self {
+        self.content = Some(content.to_string());
+        self
+    }
+
+    /// 设置测试数据和测试频道
+    pub fn test(mut self, test_data: &serde_json::Value, test_channel: &str) -> Self {
+        self.test_data = Some(test_data.to_string());
+        self.test_channel = Some(test_channel.to_string());
+        self
+    }
+}
+
+/// 加入语音频道的请求
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct JoinVoice {
+    /// 语音频道 id
+    pub channel_id: String,
+    /// 推流的 ssrc，默认由服务端分配
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio_ssrc: Option<String>,
+    /// 推流的 payload type，默认 111
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio_pt: Option<String>,
+    /// 是否使用 rtcp-mux，默认 true
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rtcp_mux: Option<bool>,
+    /// 频道密码
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+}
+
+impl JoinVoice {
+    /// 加入指定的语音频道
+    pub fn new(channel_id: &str) -> Self {
+        Self {
+            channel_id: channel_id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// 设置推流的 ssrc
+    pub fn audio_ssrc(mut self, ssrc: u32) -> Self {
+        self.audio_ssrc = Some(ssrc.to_string());
+        self
+    }
+
+    /// 设置推流的 payload type
+    pub fn audio_pt(mut self, pt: u8) -> Self {
+        self.audio_pt = Some(pt.to_string());
+        self
+    }
+
+    /// 设置是否使用 rtcp-mux
+    pub fn rtcp_mux(mut self, rtcp_mux: bool) -> Self {
+        self.rtcp_mux = Some(rtcp_mux);
+        self
+    }
+
+    /// 设置频道密码
+    pub fn password(mut self, password: &str) -> Self {
+        self.password = Some(password.to_string());
+        self
+    }
+}
+
+/// 加入语音频道后的 RTP 推流参数
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoiceConnection {
+    /// 推流地址
+    pub ip: String,
+    /// 推流端口
+    #[serde(with = "int_or_str")]
+    pub port: i64,
+    /// rtcp 端口，使用 rtcp-mux 时与 `port` 相同
+    #[serde(default, with = "int_or_str")]
+    pub rtcp_port: i64,
+    /// 是否使用 rtcp-mux
+    #[serde(default)]
+    pub rtcp_mux: bool,
+    /// 码率 (bps)
+    #[serde(default, with = "int_or_str")]
+    pub bitrate: i64,
+    /// 连接的 ssrc
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "int_or_str::option")]
+    pub ssrc: Option<i64>,
+    /// 音频推流的 ssrc
+    #[serde(with = "int_or_str")]
+    pub audio_ssrc: i64,
+    /// 推流的 payload type
+    #[serde(with = "int_or_str")]
+    pub audio_pt: i64,
+}
+
+/// 机器人所在的语音频道 (voice/list)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoiceChannel {
+    /// 频道 id
+    pub id: String,
+    /// 服务器 id
+    pub guild_id: String,
+    /// 所属分组 id
+    #[serde(default)]
+    pub parent_id: String,
+    /// 频道名称
+    #[serde(default)]
+    pub name: String,
+}
+
+/// 接口中可能以整数或字符串表示的值
+mod int_or_str {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Raw {
+        Int(i64),
+        Str(String),
+    }
+
+    pub fn serialize<S: Serializer>(value: &i64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(*value)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i64, D::Error> {
+        Raw::deserialize(deserializer)?.parse()
+    }
+
+    impl Raw {
+        fn parse<E: serde::de::Error>(self) -> Result<i64, E> {
+            match self {
+                Raw::Int(value) => Ok(value),
+                Raw::Str(value) => value.parse().map_err(E::custom),
+            }
+        }
+    }
+
+    /// 以字符串保存的 id
+    pub mod string {
+        use serde::{Deserialize, Deserializer, Serializer};
+        use super::Raw;
+
+        pub fn serialize<S: Serializer>(value: &str, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(value)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+            Ok(match Raw::deserialize(deserializer)? {
+                Raw::Int(value) => value.to_string(),
+                Raw::Str(value) => value,
+            })
+        }
+    }
+
+    /// 可以缺失的整数
+    pub mod option {
+        use serde::{Deserialize, Deserializer, Serializer};
+        use super::Raw;
+
+        pub fn serialize<S: Serializer>(value: &Option<i64>, serializer: S) -> Result<S::Ok, S::Error> {
+            match value {
+                Some(value) => super::serialize(value, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<i64>, D::Error> {
+            Option::<Raw>::deserialize(deserializer)?.map(Raw::parse).transpose()
+        }
+    }
+}
+
+/// 媒体文件上传结果
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Asset {
+    /// 文件地址
+    pub url: String,
+}
+
 /// WebSocket 信令
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Signal {
@@ -126,11 +1878,106 @@ pub struct EventData {
     pub extra: serde_json::Value,
 }
 
+impl EventData {
+    /// 是否为私聊消息
+    pub fn is_direct_message(&self) -> bool {
+        self.channel_type == "PERSON"
+    }
+
+    /// 私聊消息所在的私信会话 Code
+    pub fn chat_code(&self) -> Option<&str> {
+        self.extra.get("code").and_then(|code| code.as_str()).filter(|code| !code.is_empty())
+    }
+}
+
+/// KOOK 错误码分类
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiErrorKind {
+    /// 参数错误 (400xx)
+    InvalidParams,
+    /// 认证失败 (401xx)
+    Unauthorized,
+    /// 没有权限 (403xx)
+    Forbidden,
+    /// 资源不存在 (404xx)
+    NotFound,
+    /// 请求过于频繁 (429xx)
+    RateLimited,
+    /// 服务端错误 (5xxxx)
+    ServerError,
+    /// 其他未分类的错误码
+    Other(i32),
+}
+
+impl ApiErrorKind {
+    /// 根据 KOOK 错误码分类
+    pub fn from_code(code: i32) -> Self {
+        match code / 100 {
+            400 => ApiErrorKind::InvalidParams,
+            401 => ApiErrorKind::Unauthorized,
+            403 => ApiErrorKind::Forbidden,
+            404 => ApiErrorKind::NotFound,
+            429 => ApiErrorKind::RateLimited,
+            500..=599 => ApiErrorKind::ServerError,
+            _ => ApiErrorKind::Other(code),
+        }
+    }
+
+    /// 根据 HTTP 状态码分类
+    pub fn from_status(status: u16) -> Self {
+        match status {
+            400 => ApiErrorKind::InvalidParams,
+            401 => ApiErrorKind::Unauthorized,
+            403 => ApiErrorKind::Forbidden,
+            404 => ApiErrorKind::NotFound,
+            429 => ApiErrorKind::RateLimited,
+            500..=599 => ApiErrorKind::ServerError,
+            _ => ApiErrorKind::Other(status as i32),
+        }
+    }
+
+    /// 该类错误重试后是否可能成功
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, ApiErrorKind::RateLimited | ApiErrorKind::ServerError)
+    }
+}
+
+/// REST 请求失败的详细信息
+#[derive(Debug, Clone)]
+pub struct ApiError {
+    /// 错误分类
+    pub kind: ApiErrorKind,
+    /// KOOK 返回的错误码，HTTP 层失败时为 None
+    pub code: Option<i32>,
+    /// 错误消息
+    pub message: String,
+    /// HTTP 状态码
+    pub status: Option<u16>,
+    /// 请求路径
+    pub path: Option<String>,
+    /// 原始响应内容
+    pub body: Option<String>,
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.code, self.status) {
+            (Some(code), _) => write!(f, "KOOK API Error {}: {}", code, self.message)?,
+            (None, Some(status)) => write!(f, "HTTP {}: {}", status, self.message)?,
+            (None, None) => write!(f, "{}", self.message)?,
+        }
+        if let Some(path) = &self.path {
+            write!(f, " ({})", path)?;
+        }
+        Ok(())
+    }
+}
+
 /// KOOK 错误码枚举
 #[derive(Debug)]
 pub enum KookError {
-    /// 通用错误
-    Generic(i32, String),
+    /// API 返回的错误 (非 0 错误码或 HTTP 错误状态)
+    Api(ApiError),
     /// 网络错误
     Network(String),
     /// JSON 解析错误
@@ -146,7 +1993,7 @@ pub enum KookError {
 impl std::fmt::Display for KookError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            KookError::Generic(code, msg) => write!(f, "KOOK API Error {}: {}", code, msg),
+            KookError::Api(err) => write!(f, "{}", err),
             KookError::Network(msg) => write!(f, "Network Error: {}", msg),
             KookError::Json(msg) => write!(f, "JSON Error: {}", msg),
             KookError::WebSocket(msg) => write!(f, "WebSocket Error: {}", msg),
@@ -161,9 +2008,79 @@ impl std::error::Error for KookError {}
 impl KookError {
     /// 根据错误码创建错误
     pub fn from_code(code: i32, message: String) -> Self {
-        match code {
-            40100..=40199 => KookError::Auth(format!("Authentication failed ({}): {}", code, message)),
-            _ => KookError::Generic(code, message),
+        KookError::Api(ApiError {
+            kind: ApiErrorKind::from_code(code),
+            code: Some(code),
+            message,
+            status: None,
+            path: None,
+            body: None,
+        })
+    }
+
+    /// API 错误的详细信息
+    pub fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            KookError::Api(err) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// API 错误的分类
+    pub fn kind(&self) -> Option<ApiErrorKind> {
+        self.api_error().map(|err| err.kind)
+    }
+
+    /// KOOK 返回的错误码
+    pub fn code(&self) -> Option<i32> {
+        self.api_error().and_then(|err| err.code)
+    }
+
+    /// HTTP 状态码
+    pub fn status(&self) -> Option<u16> {
+        self.api_error().and_then(|err| err.status)
+    }
+
+    /// 出错的请求路径
+    pub fn path(&self) -> Option<&str> {
+        self.api_error().and_then(|err| err.path.as_deref())
+    }
+
+    /// 原始响应内容
+    pub fn body(&self) -> Option<&str> {
+        self.api_error().and_then(|err| err.body.as_deref())
+    }
+
+    /// 该错误重试后是否可能成功 (限速、服务端错误和网络错误)
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            KookError::Api(err) => err.kind.is_retryable(),
+            KookError::Network(_) | KookError::WebSocket(_) => true,
+            KookError::Json(_) | KookError::Auth(_) | KookError::Params(_) => false,
         }
     }
 }
+
+impl From<reqwest::Error> for KookError {
+    fn from(err: reqwest::Error) -> Self {
+        if err.is_builder() {
+            KookError::Params(err.to_string())
+        } else if err.is_decode() {
+            KookError::Json(err.to_string())
+        } else {
+            KookError::Network(err.to_string())
+        }
+    }
+}
+
+impl From<serde_json::Error> for KookError {
+    fn from(err: serde_json::Error) -> Self {
+        KookError::Json(err.to_string())
+    }
+}
+
+impl From<tokio_tungstenite::tungstenite::Error> for KookError {
+    fn from(err: tokio_tungstenite::tungstenite::Error) -> Self {
+        KookError::WebSocket(err.to_string())
+    }
+}
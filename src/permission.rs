@@ -0,0 +1,179 @@
+//! 服务器角色和频道的权限
+use bitflags::bitflags;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::models::{Channel, PermissionOverwrite, Role, UserPermissionOverwrite};
+
+bitflags! {
+    /// KOOK 权限位，接口中以整数表示
+    ///
+    /// 未在此列出的权限位会被原样保留，不会在序列化时丢失。
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Permission: u64 {
+        /// 管理员，拥有所有权限
+        const ADMIN = 1 << 0;
+        /// 管理服务器
+        const MANAGE_GUILD = 1 << 1;
+        /// 查看管理日志
+        const VIEW_AUDIT_LOG = 1 << 2;
+        /// 创建服务器邀请
+        const CREATE_INVITE = 1 << 3;
+        /// 管理邀请
+        const MANAGE_INVITE = 1 << 4;
+        /// 频道管理
+        const MANAGE_CHANNELS = 1 << 5;
+        /// 踢出用户
+        const KICK_MEMBERS = 1 << 6;
+        /// 加入服务器黑名单
+        const BAN_MEMBERS = 1 << 7;
+        /// 管理自定义表情
+        const MANAGE_EMOJIS = 1 << 8;
+        /// 修改服务器昵称
+        const CHANGE_NICKNAME = 1 << 9;
+        /// 管理角色权限
+        const MANAGE_ROLES = 1 << 10;
+        /// 查看文字、语音频道
+        const VIEW_CHANNELS = 1 << 11;
+        /// 发送文字消息
+        const SEND_MESSAGES = 1 << 12;
+        /// 管理消息
+        const MANAGE_MESSAGES = 1 << 13;
+        /// 上传文件
+        const ATTACH_FILES = 1 << 14;
+        /// 语音连接
+        const CONNECT = 1 << 15;
+        /// 语音管理
+        const MANAGE_VOICE = 1 << 16;
+        /// 提及 @全体成员、@在线成员和所有角色
+        const MENTION_EVERYONE = 1 << 17;
+        /// 添加回应
+        const ADD_REACTIONS = 1 << 18;
+        /// 跟随添加回应
+        const FOLLOW_REACTIONS = 1 << 19;
+        /// 被动连接语音频道
+        const PASSIVE_CONNECT = 1 << 20;
+        /// 仅使用按键说话
+        const PUSH_TO_TALK = 1 << 21;
+        /// 使用自由麦
+        const SPEAK_FREELY = 1 << 22;
+        /// 说话
+        const SPEAK = 1 << 23;
+        /// 服务器静音
+        const DEAFEN_MEMBERS = 1 << 24;
+        /// 服务器闭麦
+        const MUTE_MEMBERS = 1 << 25;
+        /// 修改他人昵称
+        const MANAGE_NICKNAMES = 1 << 26;
+        /// 播放伴奏
+        const PLAY_MUSIC = 1 << 27;
+        /// 屏幕分享
+        const SCREEN_SHARE = 1 << 28;
+
+        // 保留未知的权限位
+        const _ = !0;
+    }
+}
+
+impl Permission {
+    /// 所有已定义的权限位 (第 0 到 28 位)，不含保留的未知权限位
+    pub const KNOWN: Permission = Permission::from_bits_retain((1 << 29) - 1);
+
+    /// 是否拥有 `permission` 中的全部权限，管理员视为拥有所有权限
+    pub fn has(&self, permission: Permission) -> bool {
+        self.contains(Permission::ADMIN) || self.contains(permission)
+    }
+}
+
+impl Serialize for Permission {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.bits())
+    }
+}
+
+impl<'de> Deserialize<'de> for Permission {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // 部分接口以字符串形式返回权限值
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Number(u64),
+            Text(String),
+        }
+
+        let bits = match Raw::deserialize(deserializer)? {
+            Raw::Number(bits) => bits,
+            Raw::Text(text) => text.parse().map_err(serde::de::Error::custom)?,
+        };
+        Ok(Permission::from_bits_retain(bits))
+    }
+}
+
+/// 计算成员在服务器中的权限：@全体成员 角色与成员拥有的所有角色的权限之和
+///
+/// 管理员会得到所有已定义的权限 ([`Permission::KNOWN`])。
+/// 服务器主拥有所有权限，调用方需要自行判断 (`Guild::user_id`)。
+pub fn guild_permissions(roles: &[Role], member_roles: &[i32]) -> Permission {
+    let permissions = roles.iter()
+        .filter(|role| role.is_everyone() || member_roles.contains(&role.role_id))
+        .fold(Permission::empty(), |acc, role| acc | role.permissions);
+    if permissions.contains(Permission::ADMIN) {
+        permissions | Permission::KNOWN
+    } else {
+        permissions
+    }
+}
+
+/// 在服务器权限的基础上应用频道的权限覆写
+///
+/// 依次应用 @全体成员 的覆写、成员所有角色的覆写 (合并后应用) 和针对该用户的覆写，
+/// 后应用的允许会覆盖先应用的禁止。拥有管理员权限时不受覆写影响。
+pub fn apply_overwrites(
+    base: Permission,
+    user_id: &str,
+    member_roles: &[i32],
+    role_overwrites: &[PermissionOverwrite],
+    user_overwrites: &[UserPermissionOverwrite],
+) -> Permission {
+    if base.contains(Permission::ADMIN) {
+        return base | Permission::KNOWN;
+    }
+
+    let mut permissions = base;
+    if let Some(everyone) = role_overwrites.iter().find(|o| o.role_id == 0) {
+        permissions = (permissions - everyone.deny) | everyone.allow;
+    }
+
+    let (allow, deny) = role_overwrites.iter()
+        .filter(|o| o.role_id != 0 && member_roles.contains(&o.role_id))
+        .fold((Permission::empty(), Permission::empty()), |(allow, deny), o| {
+            (allow | o.allow, deny | o.deny)
+        });
+    permissions = (permissions - deny) | allow;
+
+    if let Some(user) = user_overwrites.iter().find(|o| o.user.id == user_id) {
+        permissions = (permissions - user.deny) | user.allow;
+    }
+    permissions
+}
+
+/// 计算成员在频道中的实际权限
+///
+/// 频道与分组同步权限 (`permission_sync == 1`) 且提供了 `parent` 时使用分组的权限覆写。
+pub fn channel_permissions(
+    roles: &[Role],
+    user_id: &str,
+    member_roles: &[i32],
+    channel: &Channel,
+    parent: Option<&Channel>,
+) -> Permission {
+    let source = match parent {
+        Some(parent) if channel.is_permission_synced() && parent.id == channel.parent_id => parent,
+        _ => channel,
+    };
+    apply_overwrites(
+        guild_permissions(roles, member_roles),
+        user_id,
+        member_roles,
+        &source.permission_overwrites,
+        &source.permission_users,
+    )
+}
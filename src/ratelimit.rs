@@ -0,0 +1,164 @@
+//! 速率限制追踪，按照官方文档中的 X-Rate-Limit-* 响应头管理各个限速桶
+use reqwest::header::HeaderMap;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::time::{sleep, Instant};
+
+/// 收到 429 但响应头中没有重置时间时的默认等待时间
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(1);
+
+/// 从响应头解析出的速率限制信息
+#[derive(Debug, Clone, Default)]
+pub struct RateLimitInfo {
+    /// 一段时间内允许的最大请求数 (X-Rate-Limit-Limit)
+    pub limit: Option<u32>,
+    /// 一段时间内还剩下的请求数 (X-Rate-Limit-Remaining)
+    pub remaining: Option<u32>,
+    /// 距离回复到最大请求数的时间 (X-Rate-Limit-Reset)
+    pub reset_after: Option<Duration>,
+    /// 请求所属的限速桶 (X-Rate-Limit-Bucket)
+    pub bucket: Option<String>,
+    /// 是否触发了全局限速 (X-Rate-Limit-Global)
+    pub global: bool,
+}
+
+impl RateLimitInfo {
+    /// 从 HTTP 响应头解析速率限制信息
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let get = |name: &str| headers.get(name).and_then(|v| v.to_str().ok()).map(str::trim);
+
+        Self {
+            limit: get("X-Rate-Limit-Limit").and_then(|v| v.parse().ok()),
+            remaining: get("X-Rate-Limit-Remaining").and_then(|v| v.parse().ok()),
+            reset_after: get("X-Rate-Limit-Reset")
+                .and_then(|v| v.parse::<f64>().ok())
+                .and_then(|secs| Duration::try_from_secs_f64(secs).ok()),
+            bucket: get("X-Rate-Limit-Bucket").filter(|v| !v.is_empty()).map(str::to_string),
+            global: headers.contains_key("X-Rate-Limit-Global"),
+        }
+    }
+
+    /// 触发限速后应等待的时间
+    pub fn retry_after(&self) -> Duration {
+        self.reset_after.unwrap_or(DEFAULT_RETRY_AFTER)
+    }
+}
+
+/// 单个限速桶的状态
+#[derive(Debug, Clone)]
+pub struct Bucket {
+    /// 桶的最大请求数
+    pub limit: u32,
+    /// 桶内剩余的请求数
+    pub remaining: u32,
+    /// 桶重置的时间点
+    pub reset_at: Instant,
+}
+
+#[derive(Debug, Default)]
+struct RateLimiterState {
+    /// 限速桶名称 -> 桶状态
+    buckets: HashMap<String, Bucket>,
+    /// 请求路径 -> 限速桶名称
+    routes: HashMap<String, String>,
+    /// 全局限速解除的时间点
+    global_reset_at: Option<Instant>,
+}
+
+/// 速率限制器，记录每个限速桶的剩余额度，并在额度耗尽时主动延迟请求
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    state: Mutex<RateLimiterState>,
+}
+
+impl RateLimiter {
+    /// 创建空的速率限制器
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 在发送请求前调用，如果对应的限速桶已耗尽则等待其重置
+    pub async fn acquire(&self, path: &str) {
+        while let Some(delay) = self.try_acquire(path) {
+            log::debug!("请求 {} 触发限速，等待 {:?}", path, delay);
+            sleep(delay).await;
+        }
+    }
+
+    /// 尝试占用一次请求额度，返回 `Some` 表示需要等待的时间
+    fn try_acquire(&self, path: &str) -> Option<Duration> {
+        let mut state = self.state.lock().unwrap();
+        let now = Instant::now();
+
+        if let Some(reset_at) = state.global_reset_at {
+            if reset_at > now {
+                return Some(reset_at - now);
+            }
+            state.global_reset_at = None;
+        }
+
+        let name = state.routes.get(route_key(path))?.clone();
+        let bucket = state.buckets.get_mut(&name)?;
+
+        if bucket.reset_at <= now {
+            bucket.remaining = bucket.limit;
+        }
+        if bucket.remaining == 0 {
+            return Some(bucket.reset_at - now);
+        }
+        bucket.remaining -= 1;
+        None
+    }
+
+    /// 根据响应头更新限速状态
+    pub fn update(&self, path: &str, info: &RateLimitInfo) {
+        let mut state = self.state.lock().unwrap();
+        let now = Instant::now();
+
+        if info.global {
+            state.global_reset_at = Some(now + info.retry_after());
+        }
+
+        let name = match &info.bucket {
+            Some(name) => name.clone(),
+            None => return,
+        };
+        state.routes.insert(route_key(path).to_string(), name.clone());
+
+        if let (Some(limit), Some(remaining)) = (info.limit, info.remaining) {
+            let reset_at = now + info.reset_after.unwrap_or_default();
+            state.buckets.insert(name, Bucket { limit, remaining, reset_at });
+        }
+    }
+
+    /// 记录一次 429 响应，将对应的桶标记为耗尽
+    pub fn mark_exhausted(&self, path: &str, info: &RateLimitInfo) {
+        self.update(path, info);
+
+        let mut state = self.state.lock().unwrap();
+        let reset_at = Instant::now() + info.retry_after();
+        // 响应头没有给出桶名称时，以请求路径作为独立的桶
+        let name = state.routes
+            .entry(route_key(path).to_string())
+            .or_insert_with(|| route_key(path).to_string())
+            .clone();
+        let bucket = state.buckets.entry(name).or_insert(Bucket {
+            limit: info.limit.unwrap_or(1),
+            remaining: 0,
+            reset_at,
+        });
+        bucket.remaining = 0;
+        bucket.reset_at = bucket.reset_at.max(reset_at);
+    }
+
+    /// 获取指定限速桶的当前状态
+    pub fn bucket(&self, name: &str) -> Option<Bucket> {
+        self.state.lock().unwrap().buckets.get(name).cloned()
+    }
+}
+
+/// 将请求路径规范化为路由键，忽略开头的 `/`
+fn route_key(path: &str) -> &str {
+    path.trim_start_matches('/')
+}
@@ -0,0 +1,94 @@
+//! REST 请求的自动重试策略
+use reqwest::Method;
+use std::time::Duration;
+use crate::models::{ApiErrorKind, KookError};
+
+/// 重试策略，决定哪些请求、哪些错误可以重试以及重试前的等待时间
+///
+/// 默认只重试 GET/HEAD 请求，触发重试的错误为限速、服务端错误和网络错误。
+/// KOOK 不会根据 `nonce` 去重，POST 等非幂等请求即使带有 `nonce` 也不会重试。
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// 最大尝试次数 (包括第一次请求)，1 表示不重试
+    pub max_attempts: u32,
+    /// 第一次重试前的等待时间
+    pub initial_backoff: Duration,
+    /// 单次等待时间的上限
+    pub max_backoff: Duration,
+    /// 每次重试等待时间的增长倍数
+    pub multiplier: f64,
+    /// 是否在等待时间上加入随机抖动
+    pub jitter: bool,
+    /// 允许重试的请求方法，应只包含幂等的方法
+    pub methods: Vec<Method>,
+    /// 是否重试限速错误
+    ///
+    /// 被限速的请求不会被服务端处理，因此不受 `methods` 限制，
+    /// 等待时间取自响应头中的 `X-Rate-Limit-Reset`。
+    /// 第一次限速后的等待不计入 `max_attempts`，关闭后限速错误会直接返回。
+    pub retry_on_rate_limit: bool,
+    /// 是否重试服务端错误 (5xx)
+    pub retry_on_server_error: bool,
+    /// 是否重试网络错误
+    pub retry_on_network_error: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(10),
+            multiplier: 2.0,
+            jitter: true,
+            methods: vec![Method::GET, Method::HEAD],
+            retry_on_rate_limit: true,
+            retry_on_server_error: true,
+            retry_on_network_error: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// 不进行任何重试的策略
+    ///
+    /// 限速错误仍会等待一次重置时间后重发，需要完全关闭时可将 `retry_on_rate_limit` 设为 `false`。
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// 判断请求本身是否可以安全地重复发送
+    pub fn is_retryable_request(&self, method: &Method) -> bool {
+        self.methods.contains(method)
+    }
+
+    /// 判断错误是否应该重试
+    pub fn is_retryable_error(&self, err: &KookError) -> bool {
+        match err {
+            KookError::Api(api_err) => match api_err.kind {
+                ApiErrorKind::RateLimited => self.retry_on_rate_limit,
+                ApiErrorKind::ServerError => self.retry_on_server_error,
+                _ => false,
+            },
+            KookError::Network(_) => self.retry_on_network_error,
+            _ => false,
+        }
+    }
+
+    /// 第 `attempt` 次请求失败后，重试前需要等待的时间 (`attempt` 从 1 开始)
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exp = self.multiplier.max(1.0).powi(attempt.saturating_sub(1) as i32);
+        let max = self.max_backoff.as_secs_f64();
+        let delay = (self.initial_backoff.as_secs_f64() * exp).min(max);
+
+        if self.jitter {
+            // 在 [delay / 2, delay] 之间随机取值，避免多个客户端同时重试
+            Duration::from_secs_f64(delay / 2.0 + fastrand::f64() * delay / 2.0)
+        } else {
+            Duration::from_secs_f64(delay)
+        }
+    }
+}
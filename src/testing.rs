@@ -0,0 +1,326 @@
+//! 测试工具：本地模拟 KOOK REST 服务器 (需要开启 `testing` 特性)
+//!
+//! 模拟服务器监听本地随机端口，按照 `ApiResponse` 格式返回预设或自定义的数据，
+//! 并记录收到的每一个请求，便于在没有 Token 和网络的情况下测试机器人逻辑。
+use bytes::Bytes;
+use reqwest::Method;
+use serde_json::{json, Value};
+use std::collections::{HashMap, VecDeque};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use tokio::sync::oneshot;
+use warp::http::{HeaderMap, Response, StatusCode};
+use warp::Filter;
+use crate::client::KookClient;
+use crate::models::KookError;
+use crate::retry::RetryPolicy;
+
+/// 模拟服务器返回的响应
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    /// HTTP 状态码
+    pub status: u16,
+    /// 响应体，通常是 `ApiResponse` 格式
+    pub body: Value,
+    /// 附加的响应头，例如 `X-Rate-Limit-*`
+    pub headers: Vec<(String, String)>,
+}
+
+impl MockResponse {
+    /// 成功响应，`data` 为响应中的数据
+    pub fn ok(data: Value) -> Self {
+        Self {
+            status: 200,
+            body: json!({ "code": 0, "message": "操作成功", "data": data }),
+            headers: Vec::new(),
+        }
+    }
+
+    /// KOOK 业务错误 (HTTP 200，非 0 错误码)
+    pub fn error(code: i32, message: &str) -> Self {
+        Self {
+            status: 200,
+            body: json!({ "code": code, "message": message, "data": [] }),
+            headers: Vec::new(),
+        }
+    }
+
+    /// HTTP 错误状态
+    pub fn http(status: u16, body: Value) -> Self {
+        Self {
+            status,
+            body,
+            headers: Vec::new(),
+        }
+    }
+
+    /// 添加响应头
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+/// 模拟服务器记录的请求
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    /// 请求方法
+    pub method: Method,
+    /// 去掉 `/api` 前缀后的路径，例如 `/v3/message/create`
+    pub path: String,
+    /// 查询参数
+    pub query: Vec<(String, String)>,
+    /// 请求头
+    pub headers: HeaderMap,
+    /// 原始请求体
+    pub raw_body: Bytes,
+}
+
+impl RecordedRequest {
+    /// 以 JSON 解析请求体
+    pub fn json(&self) -> Option<Value> {
+        serde_json::from_slice(&self.raw_body).ok()
+    }
+
+    /// 获取查询参数
+    pub fn query_param(&self, name: &str) -> Option<&str> {
+        self.query.iter().find(|(k, _)| k == name).map(|(_, v)| v.as_str())
+    }
+}
+
+/// 某个路由上的预设响应
+#[derive(Default)]
+struct Route {
+    /// 只使用一次的响应，按顺序消耗
+    once: VecDeque<MockResponse>,
+    /// 一次性响应耗尽后一直使用的响应
+    always: Option<MockResponse>,
+}
+
+#[derive(Default)]
+struct MockState {
+    routes: HashMap<(Method, String), Route>,
+    requests: Vec<RecordedRequest>,
+}
+
+/// 本地模拟 KOOK REST 服务器，drop 时自动关闭
+pub struct MockServer {
+    addr: SocketAddr,
+    state: Arc<Mutex<MockState>>,
+    shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl MockServer {
+    /// 在本地随机端口启动模拟服务器，并注册常用接口的默认响应
+    pub async fn start() -> Self {
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let (shutdown, rx) = oneshot::channel::<()>();
+
+        let filter_state = state.clone();
+        let routes = warp::method()
+            .and(warp::path::full())
+            .and(warp::query::raw().or(warp::any().map(String::new)).unify())
+            .and(warp::header::headers_cloned())
+            .and(warp::body::bytes())
+            .map(move |method, path: warp::path::FullPath, query: String, headers, body| {
+                handle_request(&filter_state, method, path.as_str(), &query, headers, body)
+            });
+
+        let (addr, server) = warp::serve(routes)
+            .bind_with_graceful_shutdown(([127, 0, 0, 1], 0), async {
+                rx.await.ok();
+            });
+        tokio::spawn(server);
+
+        let server = Self {
+            addr,
+            state,
+            shutdown: Some(shutdown),
+        };
+        server.register_defaults();
+        server
+    }
+
+    /// 服务器监听的地址
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// 可用于 `KookClientBuilder::base_url` 的 API 地址
+    pub fn base_url(&self) -> String {
+        format!("http://{}/api", self.addr)
+    }
+
+    /// 创建指向模拟服务器的客户端 (关闭自动重试，便于断言请求次数)
+    pub fn client(&self) -> Result<KookClient, KookError> {
+        KookClient::builder("mock-token")
+            .base_url(&self.base_url())
+            .https_only(false)
+            .retry_policy(RetryPolicy::none())
+            .build()
+    }
+
+    /// 设置某个接口一直返回的数据，`path` 形如 `/v3/user/me`
+    pub fn mock(&self, method: Method, path: &str, data: Value) {
+        self.mock_response(method, path, MockResponse::ok(data));
+    }
+
+    /// 设置某个接口一直返回的响应
+    pub fn mock_response(&self, method: Method, path: &str, response: MockResponse) {
+        let mut state = self.state.lock().unwrap();
+        state.routes.entry((method, normalize_path(path))).or_default().always = Some(response);
+    }
+
+    /// 追加一个只使用一次的响应，优先于 `mock` 设置的响应，可用于模拟限速或偶发错误
+    pub fn mock_once(&self, method: Method, path: &str, response: MockResponse) {
+        let mut state = self.state.lock().unwrap();
+        state.routes.entry((method, normalize_path(path))).or_default().once.push_back(response);
+    }
+
+    /// 收到的所有请求
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    /// 收到的发往指定路径的请求
+    pub fn requests_to(&self, path: &str) -> Vec<RecordedRequest> {
+        let path = normalize_path(path);
+        self.state.lock().unwrap().requests.iter()
+            .filter(|req| req.path == path)
+            .cloned()
+            .collect()
+    }
+
+    /// 清空请求记录
+    pub fn clear_requests(&self) {
+        self.state.lock().unwrap().requests.clear();
+    }
+
+    /// 注册常用接口的默认响应
+    fn register_defaults(&self) {
+        self.mock(Method::GET, "/v3/user/me", json!({
+            "id": "1000000001",
+            "username": "MockBot",
+            "identify_num": "0001",
+            "online": true,
+            "bot": true,
+            "status": 0,
+            "avatar": "https://img.kookapp.cn/avatars/mock.png",
+            "vip_avatar": null,
+            "nickname": "MockBot",
+            "roles": [],
+            "is_vip": false,
+            "vip_amp": false,
+            "tag_info": null
+        }));
+        self.mock(Method::GET, "/v3/gateway/index", json!({
+            "url": format!("ws://{}/gateway", self.addr)
+        }));
+        self.mock(Method::GET, "/v3/guild/list", paged(vec![json!({
+            "id": "2000000001",
+            "name": "Mock Guild",
+            "topic": "",
+            "user_id": "1000000002",
+            "icon": "",
+            "notify_type": 2,
+            "region": "beijing",
+            "enable_open": false,
+            "open_id": "0",
+            "default_channel_id": "3000000001",
+            "welcome_channel_id": "0"
+        })]));
+        self.mock(Method::GET, "/v3/channel/list", paged(vec![json!({
+            "id": "3000000001",
+            "name": "general",
+            "user_id": "1000000002",
+            "guild_id": "2000000001",
+            "topic": "",
+            "is_category": false,
+            "parent_id": "",
+            "level": 100,
+            "slow_mode": 0,
+            "type": 1,
+            "permission_overwrites": [],
+            "permission_users": [],
+            "permission_sync": 1,
+            "has_password": false
+        })]));
+        self.mock(Method::POST, "/v3/message/create", json!({
+            "msg_id": "00000000-0000-0000-0000-000000000001",
+            "msg_timestamp": 1_700_000_000_000i64,
+            "nonce": ""
+        }));
+        self.mock(Method::POST, "/v3/asset/create", json!({
+            "url": "https://img.kookapp.cn/assets/mock.png"
+        }));
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+    }
+}
+
+/// 生成只有一页的分页数据
+pub fn paged(items: Vec<Value>) -> Value {
+    let total = items.len();
+    json!({
+        "items": items,
+        "meta": { "page": 1, "page_total": 1, "page_size": 50, "total": total },
+        "sort": {}
+    })
+}
+
+/// 去掉 `/api` 前缀，统一以 `/` 开头
+fn normalize_path(path: &str) -> String {
+    let path = path.strip_prefix("/api").unwrap_or(path);
+    format!("/{}", path.trim_start_matches('/'))
+}
+
+/// 记录请求并返回匹配的预设响应
+fn handle_request(
+    state: &Mutex<MockState>,
+    method: Method,
+    path: &str,
+    query: &str,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response<String> {
+    let path = normalize_path(path);
+    let query = url_decode_pairs(query);
+
+    let mut state = state.lock().unwrap();
+    state.requests.push(RecordedRequest {
+        method: method.clone(),
+        path: path.clone(),
+        query,
+        headers,
+        raw_body: body,
+    });
+
+    let response = state.routes.get_mut(&(method, path.clone()))
+        .and_then(|route| route.once.pop_front().or_else(|| route.always.clone()))
+        .unwrap_or_else(|| MockResponse::http(404, json!({
+            "code": 40400,
+            "message": format!("mock route not found: {}", path),
+            "data": []
+        })));
+
+    let mut builder = Response::builder()
+        .status(StatusCode::from_u16(response.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR))
+        .header("Content-Type", "application/json");
+    for (name, value) in &response.headers {
+        builder = builder.header(name.as_str(), value.as_str());
+    }
+    builder.body(response.body.to_string()).unwrap()
+}
+
+/// 解析查询字符串
+fn url_decode_pairs(query: &str) -> Vec<(String, String)> {
+    reqwest::Url::parse(&format!("http://localhost/?{}", query))
+        .map(|url| url.query_pairs().into_owned().collect())
+        .unwrap_or_default()
+}
@@ -1,14 +1,22 @@
-use tokio_tungstenite::{connect_async, tungstenite::Message};
+use tokio_tungstenite::{client_async_tls, connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
 use futures_util::{StreamExt, SinkExt};
 use serde_json::Value;
 use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
 use tokio::time::{sleep, timeout, Instant};
+use tokio_socks::tcp::Socks5Stream;
+use percent_encoding::percent_decode_str;
+use base64::Engine;
 use std::collections::HashMap;
 use flate2::read::ZlibDecoder;
 use std::io::Read;
 use crate::models::*;
 use crate::client::KookClient;
 
+/// 网关连接使用的 WebSocket 流
+type GatewayStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
 /// WebSocket 客户端，实现完整的 KOOK WebSocket 协议
 pub struct KookWebSocketClient {
     client: KookClient,
@@ -77,8 +85,7 @@ impl KookWebSocketClient {
 
         // 3. 建立 WebSocket 连接
         log::info!("连接到 WebSocket: {}", ws_url);
-        let (ws_stream, _) = connect_async(&ws_url).await
-            .map_err(|e| KookError::WebSocket(format!("连接失败: {}", e)))?;
+        let ws_stream = connect_gateway(&ws_url, self.client.proxy()).await?;
 
         let (write, mut read) = ws_stream.split();
 
@@ -113,8 +120,8 @@ impl KookWebSocketClient {
     /// 启动事件循环
     async fn start_event_loop<H: EventHandler>(
         &mut self,
-        mut write: futures_util::stream::SplitSink<tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>, Message>,
-        mut read: futures_util::stream::SplitStream<tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>>,
+        mut write: futures_util::stream::SplitSink<GatewayStream, Message>,
+        mut read: futures_util::stream::SplitStream<GatewayStream>,
         handler: &H,
     ) -> Result<(), KookError> {
         let mut last_ping = Instant::now();
@@ -278,3 +285,92 @@ impl KookWebSocketClient {
         }
     }
 }
+
+
+/// 连接 WebSocket 网关，配置了代理时先通过代理建立隧道
+async fn connect_gateway(ws_url: &str, proxy: Option<&str>) -> Result<GatewayStream, KookError> {
+    let proxy_url = match proxy {
+        Some(proxy_url) => proxy_url,
+        None => {
+            let (ws_stream, _) = connect_async(ws_url).await
+                .map_err(|e| KookError::WebSocket(format!("连接失败: {}", e)))?;
+            return Ok(ws_stream);
+        }
+    };
+
+    let target = reqwest::Url::parse(ws_url)
+        .map_err(|e| KookError::WebSocket(format!("无效的网关地址: {}", e)))?;
+    let host = target.host_str()
+        .ok_or_else(|| KookError::WebSocket("网关地址缺少主机名".to_string()))?;
+    let port = target.port_or_known_default().unwrap_or(443);
+
+    log::debug!("通过代理 {} 连接网关 {}:{}", proxy_url, host, port);
+    let stream = proxy_tunnel(proxy_url, host, port).await?;
+    let (ws_stream, _) = client_async_tls(ws_url, stream).await
+        .map_err(|e| KookError::WebSocket(format!("连接失败: {}", e)))?;
+    Ok(ws_stream)
+}
+
+/// 通过 HTTP CONNECT 或 SOCKS5 代理建立到目标地址的 TCP 隧道
+///
+/// 网关连接使用该函数穿过 `KookClientBuilder::proxy` 配置的代理，
+/// 也可以用于自行建立的 WebSocket 连接。
+pub async fn proxy_tunnel(proxy_url: &str, host: &str, port: u16) -> Result<TcpStream, KookError> {
+    let proxy = reqwest::Url::parse(proxy_url)
+        .map_err(|e| KookError::Params(format!("无效的代理地址 {}: {}", proxy_url, e)))?;
+    let proxy_host = proxy.host_str()
+        .ok_or_else(|| KookError::Params(format!("代理地址缺少主机名: {}", proxy_url)))?;
+    // URL 中的用户名和密码是百分号编码的
+    let username = percent_decode_str(proxy.username()).decode_utf8_lossy();
+    let password = percent_decode_str(proxy.password().unwrap_or("")).decode_utf8_lossy();
+
+    match proxy.scheme() {
+        "socks5" | "socks5h" => {
+            let proxy_addr = (proxy_host, proxy.port().unwrap_or(1080));
+            let stream = if username.is_empty() {
+                Socks5Stream::connect(proxy_addr, (host, port)).await
+            } else {
+                Socks5Stream::connect_with_password(proxy_addr, (host, port), &username, &password).await
+            }
+            .map_err(|e| KookError::WebSocket(format!("SOCKS5 代理连接失败: {}", e)))?;
+            Ok(stream.into_inner())
+        }
+        "http" => {
+            let mut stream = TcpStream::connect((proxy_host, proxy.port().unwrap_or(80))).await
+                .map_err(|e| KookError::WebSocket(format!("代理连接失败: {}", e)))?;
+
+            let mut request = format!("CONNECT {host}:{port} HTTP/1.1\r\nHost: {host}:{port}\r\n");
+            if !username.is_empty() {
+                let credentials = base64::engine::general_purpose::STANDARD
+                    .encode(format!("{}:{}", username, password));
+                request.push_str(&format!("Proxy-Authorization: Basic {}\r\n", credentials));
+            }
+            request.push_str("\r\n");
+            stream.write_all(request.as_bytes()).await
+                .map_err(|e| KookError::WebSocket(format!("发送 CONNECT 请求失败: {}", e)))?;
+
+            // 逐字节读取响应头，避免把隧道中的后续数据读走
+            let mut response = Vec::new();
+            let mut byte = [0u8; 1];
+            while !response.ends_with(b"\r\n\r\n") {
+                if response.len() > 8192 {
+                    return Err(KookError::WebSocket("代理响应头过长".to_string()));
+                }
+                let n = stream.read(&mut byte).await
+                    .map_err(|e| KookError::WebSocket(format!("读取代理响应失败: {}", e)))?;
+                if n == 0 {
+                    return Err(KookError::WebSocket("代理在建立隧道前关闭了连接".to_string()));
+                }
+                response.push(byte[0]);
+            }
+
+            let response = String::from_utf8_lossy(&response);
+            let status_line = response.lines().next().unwrap_or_default();
+            match status_line.split_whitespace().nth(1) {
+                Some(code) if code.starts_with('2') => Ok(stream),
+                _ => Err(KookError::WebSocket(format!("代理拒绝建立隧道: {}", status_line))),
+            }
+        }
+        scheme => Err(KookError::Params(format!("WebSocket 不支持 {} 代理", scheme))),
+    }
+}
@@ -0,0 +1,255 @@
+//! 使用本地模拟服务器测试各接口发送的请求
+#![cfg(feature = "testing")]
+use kook_sdk::testing::{paged, MockServer};
+use kook_sdk::*;
+use reqwest::Method;
+use serde_json::{json, Value};
+
+/// 从 multipart 请求体中取出指定字段的头部和内容
+fn form_part(body: &[u8], name: &str) -> Option<(String, Vec<u8>)> {
+    let body = String::from_utf8_lossy(body);
+    let disposition = format!("name=\"{}\"", name);
+    body.split("\r\n--").find(|part| part.contains(&disposition)).map(|part| {
+        let (head, content) = part.split_once("\r\n\r\n").unwrap_or((part, ""));
+        (head.to_string(), content.as_bytes().to_vec())
+    })
+}
+
+#[tokio::test]
+async fn upload_asset_from_bytes() {
+    let server = MockServer::start().await;
+    let client = server.client().unwrap();
+
+    let asset = client.upload_asset(AssetUpload::from_bytes(b"GIF89a".to_vec(), "cat.gif")).await.unwrap();
+
+    assert_eq!(asset.url, "https://img.kookapp.cn/assets/mock.png");
+    let requests = server.requests_to("/v3/asset/create");
+    assert_eq!(requests.len(), 1);
+    let content_type = requests[0].headers["Content-Type"].to_str().unwrap();
+    assert!(content_type.starts_with("multipart/form-data; boundary="));
+
+    let (head, content) = form_part(&requests[0].raw_body, "file").unwrap();
+    assert!(head.contains("filename=\"cat.gif\""));
+    assert!(head.contains("Content-Type: image/gif"));
+    assert_eq!(content, b"GIF89a");
+}
+
+#[tokio::test]
+async fn upload_asset_from_path() {
+    let server = MockServer::start().await;
+    let client = server.client().unwrap();
+    let path = std::env::temp_dir().join(format!("kook_sdk_upload_{}.txt", std::process::id()));
+    std::fs::write(&path, "hello").unwrap();
+
+    let result = client.upload_asset(AssetUpload::from_path(&path)).await;
+    std::fs::remove_file(&path).unwrap();
+    result.unwrap();
+
+    let requests = server.requests_to("/v3/asset/create");
+    let (head, content) = form_part(&requests[0].raw_body, "file").unwrap();
+    let file_name = path.file_name().unwrap().to_str().unwrap();
+    assert!(head.contains(&format!("filename=\"{}\"", file_name)));
+    assert!(head.contains("Content-Type: text/plain"));
+    assert_eq!(content, b"hello");
+}
+
+#[tokio::test]
+async fn upload_asset_missing_file_is_rejected() {
+    let server = MockServer::start().await;
+    let client = server.client().unwrap();
+
+    let err = client.upload_asset(AssetUpload::from_path("/nonexistent/kook_sdk.png")).await.unwrap_err();
+
+    assert!(matches!(err, KookError::Params(_)));
+    assert!(server.requests().is_empty());
+}
+
+/// 私聊消息事件，`extra` 中可以带有私信会话 Code
+fn direct_message_event(channel_type: &str, extra: Value) -> EventData {
+    serde_json::from_value(json!({
+        "channel_type": channel_type,
+        "type": 1,
+        "target_id": "1000000001",
+        "author_id": "2000000002",
+        "content": "hi",
+        "msg_id": "00000000-0000-0000-0000-0000000000aa",
+        "msg_timestamp": 1_700_000_000_000i64,
+        "nonce": "",
+        "extra": extra
+    }))
+    .unwrap()
+}
+
+async fn direct_message_server() -> MockServer {
+    let server = MockServer::start().await;
+    server.mock(Method::POST, "/v3/direct-message/create", json!({
+        "msg_id": "00000000-0000-0000-0000-0000000000bb",
+        "msg_timestamp": 1_700_000_000_001i64,
+        "nonce": ""
+    }));
+    server
+}
+
+#[tokio::test]
+async fn reply_direct_message_uses_chat_code() {
+    let server = direct_message_server().await;
+    let client = server.client().unwrap();
+    let event = direct_message_event("PERSON", json!({ "type": 1, "code": "chat-code-1" }));
+
+    let created = client.reply_direct_message(&event, "hello").await.unwrap();
+
+    assert_eq!(created.msg_id, "00000000-0000-0000-0000-0000000000bb");
+    let body = server.requests_to("/v3/direct-message/create")[0].json().unwrap();
+    assert_eq!(body["chat_code"], "chat-code-1");
+    assert!(body.get("target_id").is_none());
+    assert_eq!(body["content"], "hello");
+    assert_eq!(body["quote"], "00000000-0000-0000-0000-0000000000aa");
+}
+
+#[tokio::test]
+async fn reply_direct_message_falls_back_to_author() {
+    let server = direct_message_server().await;
+    let client = server.client().unwrap();
+    let event = direct_message_event("PERSON", json!({ "type": 1, "code": "" }));
+
+    client.reply_direct_message(&event, "hello").await.unwrap();
+
+    let body = server.requests_to("/v3/direct-message/create")[0].json().unwrap();
+    assert_eq!(body["target_id"], "2000000002");
+    assert!(body.get("chat_code").is_none());
+    assert_eq!(body["quote"], "00000000-0000-0000-0000-0000000000aa");
+}
+
+#[tokio::test]
+async fn reply_direct_message_rejects_channel_messages() {
+    let server = direct_message_server().await;
+    let client = server.client().unwrap();
+    let event = direct_message_event("GROUP", json!({ "type": 1 }));
+
+    let err = client.reply_direct_message(&event, "hello").await.unwrap_err();
+
+    assert!(matches!(err, KookError::Params(_)));
+    assert!(server.requests().is_empty());
+}
+
+#[tokio::test]
+async fn guild_user_query_params() {
+    let server = MockServer::start().await;
+    server.mock(Method::GET, "/v3/guild/user-list", paged(Vec::new()));
+    let client = server.client().unwrap();
+
+    let filter = GuildUserQuery {
+        search: Some("小明 & co".to_string()),
+        role_id: Some(112),
+        mobile_verified: Some(false),
+        active_time: Some(SortOrder::Desc),
+        joined_at: Some(SortOrder::Asc),
+        ..GuildUserQuery::default()
+    };
+    client.get_guild_users("1000000001", &filter, &PageParams::default()).await.unwrap();
+
+    let request = &server.requests_to("/v3/guild/user-list")[0];
+    assert_eq!(request.query_param("guild_id"), Some("1000000001"));
+    assert_eq!(request.query_param("search"), Some("小明 & co"));
+    assert_eq!(request.query_param("role_id"), Some("112"));
+    assert_eq!(request.query_param("mobile_verified"), Some("0"));
+    assert_eq!(request.query_param("active_time"), Some("1"));
+    assert_eq!(request.query_param("joined_at"), Some("0"));
+    for name in ["channel_id", "filter_user_id", "page", "page_size"] {
+        assert_eq!(request.query_param(name), None, "{} 不应出现在查询参数中", name);
+    }
+}
+
+#[tokio::test]
+async fn create_invite_sends_integer_enums() {
+    let server = MockServer::start().await;
+    server.mock(Method::POST, "/v3/invite/create", json!({ "url": "https://kook.top/ab12cd" }));
+    let client = server.client().unwrap();
+
+    let target = InviteTarget::Channel("3000000001".to_string());
+    client.create_invite(&target, InviteDuration::SevenDays, InviteUses::TwentyFive).await.unwrap();
+    let target = InviteTarget::Guild("1000000001".to_string());
+    client.create_invite(&target, InviteDuration::Never, InviteUses::Unlimited).await.unwrap();
+
+    let requests = server.requests_to("/v3/invite/create");
+    assert_eq!(requests[0].json().unwrap(), json!({
+        "channel_id": "3000000001",
+        "duration": 604800,
+        "setting_times": 25
+    }));
+    assert_eq!(requests[1].json().unwrap(), json!({
+        "guild_id": "1000000001",
+        "duration": 0,
+        "setting_times": -1
+    }));
+}
+
+#[tokio::test]
+async fn create_blacklist_body() {
+    let server = MockServer::start().await;
+    server.mock(Method::POST, "/v3/blacklist/create", json!([]));
+    let client = server.client().unwrap();
+
+    client.create_blacklist("1000000001", "2000000002", Some("刷屏"), Some(7)).await.unwrap();
+    client.create_blacklist("1000000001", "2000000003", None, None).await.unwrap();
+
+    let requests = server.requests_to("/v3/blacklist/create");
+    assert_eq!(requests[0].json().unwrap(), json!({
+        "guild_id": "1000000001",
+        "target_id": "2000000002",
+        "remark": "刷屏",
+        "del_msg_days": 7
+    }));
+    assert_eq!(requests[1].json().unwrap(), json!({
+        "guild_id": "1000000001",
+        "target_id": "2000000003"
+    }));
+}
+
+#[tokio::test]
+async fn create_blacklist_rejects_more_than_seven_days() {
+    let server = MockServer::start().await;
+    let client = server.client().unwrap();
+
+    let err = client.create_blacklist("1000000001", "2000000002", None, Some(8)).await.unwrap_err();
+
+    assert!(matches!(err, KookError::Params(_)));
+    assert!(server.requests().is_empty());
+}
+
+#[tokio::test]
+async fn set_activity_body() {
+    let server = MockServer::start().await;
+    server.mock(Method::POST, "/v3/game/activity", json!([]));
+    let client = server.client().unwrap();
+
+    client.set_activity(&Activity::Playing { game_id: 1001 }).await.unwrap();
+    client.set_activity(&Activity::Listening {
+        software: MusicSoftware::QQMusic,
+        singer: "周杰伦".to_string(),
+        music_name: "晴天".to_string(),
+    }).await.unwrap();
+
+    let requests = server.requests_to("/v3/game/activity");
+    assert_eq!(requests[0].json().unwrap(), json!({ "data_type": 1, "id": 1001 }));
+    assert_eq!(requests[1].json().unwrap(), json!({
+        "data_type": 2,
+        "software": "qqmusic",
+        "singer": "周杰伦",
+        "music_name": "晴天"
+    }));
+}
+
+#[test]
+fn guild_badge_url_encodes_guild_id() {
+    let client = KookClient::builder("mock-token").build().unwrap();
+
+    assert_eq!(
+        client.guild_badge_url("1000000001", BadgeStyle::Online),
+        "https://www.kookapp.cn/api/v3/badge/guild?guild_id=1000000001&style=1",
+    );
+    assert_eq!(
+        client.guild_badge_url("1&style=2", BadgeStyle::Online),
+        "https://www.kookapp.cn/api/v3/badge/guild?guild_id=1%26style%3D2&style=1",
+    );
+}
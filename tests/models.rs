@@ -0,0 +1,518 @@
+//! 使用 KOOK 接口返回的 JSON 样例测试模型的反序列化
+use kook_sdk::*;
+use serde::de::DeserializeOwned;
+
+/// 读取 tests/fixtures 下的样例
+fn fixture(name: &str) -> String {
+    let path = format!("{}/tests/fixtures/{}.json", env!("CARGO_MANIFEST_DIR"), name);
+    std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("无法读取 {}: {}", path, e))
+}
+
+/// 解析 `ApiResponse` 格式的样例，返回其中的数据
+fn data<T: DeserializeOwned>(name: &str) -> T {
+    let response: ApiResponse<T> = serde_json::from_str(&fixture(name))
+        .unwrap_or_else(|e| panic!("无法解析 {}: {}", name, e));
+    assert_eq!(response.code, 0);
+    response.data.unwrap_or_else(|| panic!("{} 中没有 data", name))
+}
+
+/// 解析网关信令格式的事件样例
+fn event(name: &str) -> Event {
+    let signal: Signal = serde_json::from_str(&fixture(name))
+        .unwrap_or_else(|e| panic!("无法解析 {}: {}", name, e));
+    assert_eq!(signal.s, 0);
+    let data: EventData = serde_json::from_value(signal.d)
+        .unwrap_or_else(|e| panic!("无法解析 {} 中的事件数据: {}", name, e));
+    Event::from(data)
+}
+
+#[test]
+fn user_me() {
+    let user: User = data("user_me");
+    assert_eq!(user.id, "2418200000");
+    assert!(user.bot);
+    assert_eq!(user.mobile_verified, Some(true));
+    assert_eq!(user.tag_info.as_ref().unwrap()["text"], "机器人");
+    assert_eq!(user.other["client_id"], "xxxxxx");
+    assert_eq!(user.other["os"], "Websocket");
+}
+
+#[test]
+fn user_view_in_guild() {
+    let member: GuildMember = data("user_view");
+    assert_eq!(member.user.nickname, "小助手");
+    assert_eq!(member.user.roles, vec![111, 112]);
+    assert_eq!(member.joined_at, 1612345678000);
+    assert_eq!(member.active_time, 1612345678000);
+    assert!(member.user.has_role(112));
+    assert!(!member.user.other.contains_key("joined_at"));
+}
+
+#[test]
+fn user_with_missing_fields() {
+    let user: User = data("user_minimal");
+    assert_eq!(user.username, "tz-un");
+    assert_eq!(user.identify_num, "");
+    assert!(!user.online);
+    assert!(user.vip_avatar.is_none());
+    assert!(user.roles.is_empty());
+    assert_eq!(user.tag_info, Some(serde_json::json!("官方")));
+}
+
+#[test]
+fn user_unknown_fields_round_trip() {
+    let user: User = data("user_me");
+    let value = serde_json::to_value(&user).unwrap();
+    assert_eq!(value["invited_count"], 0);
+    assert_eq!(value["mobile_prefix"], "86");
+}
+
+#[test]
+fn user_online_status() {
+    let status: OnlineStatus = data("user_online_status");
+    assert!(status.online);
+    assert_eq!(status.online_os, vec!["Websocket"]);
+}
+
+#[test]
+fn guild_list() {
+    let guilds: PagedResponse<Guild> = data("guild_list");
+    assert_eq!(guilds.meta.total, 2);
+    let guild = &guilds.items[0];
+    assert_eq!(guild.open_id, "0");
+    assert_eq!(guild.default_channel_id, "4969700000000000");
+    assert_eq!(guild.other["boost_num"], 0);
+
+    let partial = &guilds.items[1];
+    assert_eq!(partial.name, "Partial");
+    assert_eq!(partial.user_id, "");
+    assert!(!partial.enable_open);
+}
+
+#[test]
+fn guild_view() {
+    let view: GuildView = data("guild_view");
+    assert_eq!(view.guild.open_id, "1600000");
+    assert_eq!(view.boost_num, 3);
+    assert_eq!(view.level, 1);
+    assert_eq!(view.roles.len(), 2);
+    assert!(view.roles[0].is_everyone());
+    assert!(view.roles[1].hoist);
+    assert!(view.roles[1].permissions.contains(Permission::ADMIN));
+    assert_eq!(view.channels[0].name, "大厅");
+    assert!(view.guild.other.contains_key("emojis"));
+    assert!(!view.guild.other.contains_key("roles"));
+}
+
+#[test]
+fn guild_user_list() {
+    let list: GuildUserList = data("guild_user_list");
+    assert_eq!(list.user_count, 1);
+    let member = &list.items[0];
+    assert_eq!(member.user.roles, vec![111, 112]);
+    assert_eq!(member.joined_at, 1610000000000);
+    assert_eq!(member.user.other["is_master"], false);
+}
+
+#[test]
+fn guild_user_list_with_missing_fields() {
+    let list: GuildUserList = data("guild_user_list_partial");
+    assert!(list.items.is_empty());
+    assert_eq!(list.meta.total, 0);
+    assert_eq!(list.user_count, 0);
+    assert_eq!(list.online_count, 0);
+    assert_eq!(list.offline_count, 0);
+}
+
+#[test]
+fn channel_view() {
+    let channel: Channel = data("channel_view");
+    assert!(channel.is_permission_synced());
+    assert_eq!(channel.permission_overwrites[0].deny, Permission::SEND_MESSAGES);
+    assert_eq!(channel.permission_users[0].user.id, "2418200000");
+    assert_eq!(channel.permission_users[0].allow, Permission::SEND_MESSAGES);
+    assert_eq!(channel.other["limit_amount"], 0);
+}
+
+#[test]
+fn channel_list_with_missing_fields() {
+    let channels: PagedResponse<Channel> = data("channel_list");
+    assert_eq!(channels.items.len(), 2);
+    let voice = &channels.items[1];
+    assert_eq!(voice.r#type, 2);
+    assert_eq!(voice.guild_id, "");
+    assert!(voice.permission_overwrites.is_empty());
+    assert_eq!(voice.other["voice_quality"], "2");
+}
+
+#[test]
+fn channel_role_index() {
+    let index: ChannelRoleIndex = data("channel_role_index");
+    assert_eq!(index.permission_sync, 1);
+    assert_eq!(index.permission_overwrites[1].allow, Permission::MANAGE_MESSAGES);
+    let user = &index.permission_users[0];
+    assert_eq!(user.allow, Permission::VIEW_CHANNELS);
+    assert_eq!(user.deny, Permission::SEND_MESSAGES);
+}
+
+#[test]
+fn channel_role_index_with_missing_fields() {
+    let index: ChannelRoleIndex = data("channel_role_index_partial");
+    assert_eq!(index.permission_sync, 0);
+    assert!(index.permission_overwrites[0].allow.is_empty());
+    assert!(index.permission_overwrites[0].deny.is_empty());
+    assert_eq!(index.permission_overwrites[1].allow, Permission::MANAGE_MESSAGES);
+    assert!(index.permission_overwrites[1].deny.is_empty());
+    let user = &index.permission_users[0];
+    assert_eq!(user.user.id, "");
+    assert!(user.allow.is_empty());
+    assert_eq!(user.deny, Permission::SEND_MESSAGES);
+}
+
+#[test]
+fn channel_role_create() {
+    let role: ChannelRole = data("channel_role_create");
+    assert_eq!(role.role_id, Some(109472));
+    assert_eq!(role.user_id, None);
+    assert!(role.allow.is_empty());
+    assert!(role.deny.is_empty());
+}
+
+#[test]
+fn channel_role_update() {
+    let role: ChannelRole = data("channel_role_update");
+    assert_eq!(role.role_id, None);
+    assert_eq!(role.user_id.as_deref(), Some("2418200000"));
+    assert_eq!(role.allow, Permission::VIEW_CHANNELS);
+    assert_eq!(role.deny, Permission::SEND_MESSAGES);
+}
+
+#[test]
+fn channel_permissions_from_fixtures() {
+    let view: GuildView = data("guild_view");
+    let channel: Channel = data("channel_view");
+    let permissions = permission::channel_permissions(&view.roles, "3121000000", &[], &channel, None);
+    assert!(permissions.contains(Permission::VIEW_CHANNELS));
+    assert!(!permissions.contains(Permission::SEND_MESSAGES));
+
+    let user_permissions = permission::channel_permissions(&view.roles, "2418200000", &[], &channel, None);
+    assert!(user_permissions.contains(Permission::SEND_MESSAGES));
+}
+
+#[test]
+fn guild_mute_list() {
+    let mutes: GuildMuteList = data("guild_mute_list");
+    assert_eq!(mutes.mic.user_ids, vec!["1261170000"]);
+    assert!(mutes.headset.user_ids.is_empty());
+}
+
+#[test]
+fn guild_boost_history() {
+    let history: PagedResponse<GuildBoost> = data("guild_boost_history");
+    let boost = &history.items[0];
+    assert_eq!(boost.end_time - boost.start_time, 2592000);
+    assert_eq!(boost.user.username, "tz-un");
+}
+
+#[test]
+fn guild_role_list() {
+    let roles: PagedResponse<Role> = data("guild_role_list");
+    let everyone = &roles.items[0];
+    assert!(everyone.is_everyone());
+    assert!(!everyone.hoist);
+    assert!(everyone.permissions.contains(Permission::VIEW_CHANNELS | Permission::SEND_MESSAGES));
+    let role = &roles.items[1];
+    assert!(role.hoist);
+    assert!(role.mentionable);
+    assert_eq!(role.color, 1752220);
+}
+
+#[test]
+fn guild_role_grant() {
+    let grant: RoleGrant = data("guild_role_grant");
+    assert_eq!(grant.roles, vec![11111, 11112]);
+}
+
+#[test]
+fn blacklist_list() {
+    let blacklist: PagedResponse<BlacklistEntry> = data("blacklist_list");
+    let entry = &blacklist.items[0];
+    assert_eq!(entry.remark, "发送广告");
+    assert_eq!(entry.user.as_ref().unwrap().username, "spam");
+}
+
+#[test]
+fn guild_emoji_list() {
+    let emojis: PagedResponse<GuildEmoji> = data("guild_emoji_list");
+    let emoji = &emojis.items[0];
+    assert_eq!(emoji.to_kmarkdown(), "(emj)cat(emj)[91686000000/8yM4mvKsJq0a0a0a]");
+    assert_eq!(emoji.as_reaction(), "91686000000/8yM4mvKsJq0a0a0a");
+}
+
+#[test]
+fn invite_list() {
+    let invites: PagedResponse<Invite> = data("invite_list");
+    let invite = &invites.items[0];
+    assert_eq!(invite.url_code, "ab12cd");
+    assert_eq!(invite.duration, InviteDuration::SevenDays as i64);
+    assert_eq!(invite.setting_times, InviteUses::Ten as i32);
+    assert_eq!(invite.using_times, 2);
+    assert_eq!(invite.remaining_times, 8);
+    assert!(invite.user.as_ref().unwrap().online);
+}
+
+#[test]
+fn invite_create() {
+    let created: InviteCreated = data("invite_create");
+    assert_eq!(created.url_code(), "ab12cd");
+}
+
+#[test]
+fn message_create() {
+    let created: MessageCreated = data("message_create");
+    assert_eq!(created.msg_id, "67637d4c-fc6b-4c2d-a3a4-4dd8a49a8d6b");
+    assert_eq!(created.nonce, "xxx");
+}
+
+#[test]
+fn message_list() {
+    let list: MessageList = data("message_list");
+    let first = &list.items[0];
+    assert_eq!(first.r#type, MessageType::KMarkdown);
+    assert_eq!(first.mention, vec!["3121000000"]);
+    assert_eq!(first.reactions[0].count, 2);
+    assert!(first.attachments.is_none());
+
+    let second = &list.items[1];
+    assert_eq!(second.r#type, MessageType::Image);
+    let attachment = second.attachments.as_ref().unwrap();
+    assert_eq!(attachment.r#type, "image");
+    assert_eq!(attachment.width, Some(640));
+    assert_eq!(second.quote.as_ref().unwrap().author.username, "tz-un");
+}
+
+#[test]
+fn message_view() {
+    let message: Message = data("message_view");
+    assert_eq!(message.r#type, MessageType::Card);
+    assert_eq!(message.channel_id.as_deref(), Some("4969700000000000"));
+    assert!(message.author.bot);
+}
+
+#[test]
+fn reaction_list() {
+    let users: Vec<ReactionUser> = data("reaction_list");
+    assert_eq!(users[0].user.username, "tz-un");
+    assert_eq!(users[0].reaction_time, 1612345678000);
+}
+
+#[test]
+fn user_chat_list() {
+    let chats: PagedResponse<UserChat> = data("user_chat_list");
+    let chat = &chats.items[0];
+    assert_eq!(chat.code, "abcdefg1234567");
+    assert_eq!(chat.unread_count, 3);
+    assert!(chat.target_info.online);
+}
+
+#[test]
+fn direct_message_list() {
+    let list: DirectMessageList = data("direct_message_list");
+    let message = &list.items[0];
+    assert_eq!(message.r#type, MessageType::Text);
+    assert_eq!(message.author_id, "3121000000");
+    assert!(message.read_status);
+}
+
+#[test]
+fn game_list() {
+    let games: PagedResponse<Game> = data("game_list");
+    assert_eq!(games.items[0].process_name, vec!["League of Legends.exe"]);
+    assert_eq!(games.items[1].name, "维护模式");
+    assert!(games.items[1].product_name.is_empty());
+}
+
+#[test]
+fn intimacy_index() {
+    let intimacy: Intimacy = data("intimacy_index");
+    assert_eq!(intimacy.score, 888);
+    assert_eq!(intimacy.img_list[0].id, "1");
+    assert_eq!(intimacy.last_modify, 0);
+}
+
+#[test]
+fn template_list() {
+    let templates: PagedResponse<MessageTemplate> = data("template_list");
+    let template = &templates.items[0];
+    assert_eq!(template.msgtype, TemplateMessageType::Card);
+    assert_eq!(template.msgtype.message_type(), MessageType::Card);
+
+    let data = serde_json::json!({ "user": "小明", "days": 7 });
+    let message = CreateMessage::from_template("4969700000000000", template, &data);
+    assert_eq!(message.template_id.as_deref(), Some("1001"));
+    assert_eq!(message.message_type, MessageType::Card);
+}
+
+#[test]
+fn voice_join() {
+    let connection: VoiceConnection = data("voice_join");
+    assert_eq!(connection.port, 1000);
+    assert_eq!(connection.rtcp_port, 1001);
+    assert_eq!(connection.ssrc, Some(2222));
+    assert_eq!(connection.audio_ssrc, 1111);
+    assert_eq!(connection.audio_pt, 111);
+    assert!(connection.rtcp_mux);
+}
+
+#[test]
+fn voice_list() {
+    let channels: PagedResponse<VoiceChannel> = data("voice_list");
+    assert_eq!(channels.items[0].name, "语音");
+}
+
+#[test]
+fn asset_create() {
+    let asset: Asset = data("asset_create");
+    assert!(asset.url.ends_with(".txt"));
+}
+
+#[test]
+fn gateway_index() {
+    let gateway: Gateway = data("gateway_index");
+    assert!(gateway.url.starts_with("wss://"));
+}
+
+#[test]
+fn event_group_kmarkdown() {
+    let Event::Message(message) = event("event_group_kmarkdown") else {
+        panic!("应解析为消息事件");
+    };
+    assert_eq!(message.message_type, MessageType::KMarkdown);
+    assert!(!message.is_direct_message());
+    assert_eq!(message.author_name(), "小助手");
+    assert_eq!(message.guild_id(), Some("91686000000"));
+    assert_eq!(message.extra.channel_name.as_deref(), Some("大厅"));
+    assert!(message.mentions("3121000000"));
+    assert!(!message.mentions("2418200000"));
+    assert_eq!(message.raw_content(), "帮助 @kook");
+    assert_eq!(message.extra.quote.as_ref().unwrap().content, "有人吗");
+    assert_eq!(message.author().other["is_ai_reduce_noise"], true);
+}
+
+#[test]
+fn event_person_text() {
+    let Event::Message(message) = event("event_person_text") else {
+        panic!("应解析为消息事件");
+    };
+    assert!(message.is_direct_message());
+    assert_eq!(message.author_name(), "kook");
+    assert_eq!(message.guild_id(), None);
+    assert_eq!(message.extra.code.as_deref(), Some("abcdefg1234567"));
+    assert_eq!(message.raw_content(), "你好");
+    assert_eq!(message.extra.attachments.as_ref().unwrap().size, Some(14));
+}
+
+#[test]
+fn event_message_tolerates_extra_drift() {
+    let mut signal: Signal = serde_json::from_str(&fixture("event_person_text")).unwrap();
+    signal.d["extra"]["mention_roles"] = serde_json::json!(["1", 2]);
+    signal.d["extra"]["mention_all"] = serde_json::json!("false");
+    signal.d["extra"]["quote"] = serde_json::json!("");
+    let data: EventData = serde_json::from_value(signal.d).unwrap();
+
+    let Event::Message(message) = Event::from(data) else {
+        panic!("应解析为消息事件");
+    };
+    assert_eq!(message.extra.mention_roles, [1, 2]);
+    assert!(!message.extra.mention_all);
+    assert!(message.extra.quote.is_none());
+    assert_eq!(message.extra.code.as_deref(), Some("abcdefg1234567"));
+}
+
+#[test]
+fn event_chat_code() {
+    let signal: Signal = serde_json::from_str(&fixture("event_person_text")).unwrap();
+    let data: EventData = serde_json::from_value(signal.d).unwrap();
+    assert!(data.is_direct_message());
+    assert_eq!(data.chat_code(), Some("abcdefg1234567"));
+}
+
+#[test]
+fn event_joined_guild() {
+    let Event::System(system) = event("event_joined_guild") else {
+        panic!("应解析为系统事件");
+    };
+    assert_eq!(system.target_id, "91686000000");
+    let SystemEventKind::JoinedGuild(joined) = system.kind else {
+        panic!("应解析为 joined_guild");
+    };
+    assert_eq!(joined.user_id, "3121000000");
+}
+
+#[test]
+fn event_message_btn_click() {
+    let Event::System(system) = event("event_message_btn_click") else {
+        panic!("应解析为系统事件");
+    };
+    let SystemEventKind::MessageBtnClick(click) = system.kind else {
+        panic!("应解析为 message_btn_click");
+    };
+    assert_eq!(click.value, "sign_in");
+    assert_eq!(click.target_id, "4969700000000000");
+    assert_eq!(click.user_info.as_ref().unwrap().username, "kook");
+}
+
+#[test]
+fn event_updated_message() {
+    let Event::System(system) = event("event_updated_message") else {
+        panic!("应解析为系统事件");
+    };
+    let SystemEventKind::UpdatedMessage(updated) = system.kind else {
+        panic!("应解析为 updated_message");
+    };
+    assert_eq!(updated.content, "**已编辑**");
+    assert_eq!(updated.updated_at, 1612345700000);
+}
+
+#[test]
+fn event_added_reaction() {
+    let Event::System(system) = event("event_added_reaction") else {
+        panic!("应解析为系统事件");
+    };
+    let SystemEventKind::AddedReaction(reaction) = system.kind else {
+        panic!("应解析为 added_reaction");
+    };
+    assert_eq!(reaction.emoji.id, "[#128077;]");
+    assert_eq!(reaction.user_id, "3121000000");
+}
+
+#[test]
+fn event_joined_channel() {
+    let Event::System(system) = event("event_joined_channel") else {
+        panic!("应解析为系统事件");
+    };
+    let SystemEventKind::JoinedChannel(joined) = system.kind else {
+        panic!("应解析为 joined_channel");
+    };
+    assert_eq!(joined.channel_id, "4969700000000002");
+}
+
+#[test]
+fn event_guild_member_online() {
+    let Event::System(system) = event("event_guild_member_online") else {
+        panic!("应解析为系统事件");
+    };
+    let SystemEventKind::GuildMemberOnline(online) = system.kind else {
+        panic!("应解析为 guild_member_online");
+    };
+    assert_eq!(online.guilds, vec!["91686000000"]);
+}
+
+#[test]
+fn event_unknown_system_falls_back_to_raw() {
+    let Event::Raw(data) = event("event_unknown_system") else {
+        panic!("未知的系统事件应保留原始数据");
+    };
+    assert_eq!(data.r#type, 255);
+    assert_eq!(data.extra["type"], "added_block_list");
+}
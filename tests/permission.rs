@@ -0,0 +1,151 @@
+//! 服务器和频道权限的计算
+use kook_sdk::permission::{self, Permission};
+use kook_sdk::*;
+use serde_json::json;
+
+fn role(role_id: i32, permissions: Permission) -> Role {
+    serde_json::from_value(json!({
+        "role_id": role_id,
+        "name": format!("role {}", role_id),
+        "permissions": permissions.bits(),
+    }))
+    .unwrap()
+}
+
+#[test]
+fn known_contains_every_named_flag() {
+    let named = Permission::all().iter_names().fold(Permission::empty(), |acc, (_, flag)| acc | flag);
+    assert_eq!(named, Permission::KNOWN);
+}
+
+#[test]
+fn admin_gets_only_known_permissions() {
+    let roles = [role(0, Permission::VIEW_CHANNELS), role(1, Permission::ADMIN)];
+
+    let permissions = permission::guild_permissions(&roles, &[1]);
+
+    assert_eq!(permissions, Permission::KNOWN);
+    assert_eq!(permissions.bits(), (1 << 29) - 1);
+    assert!(permissions.contains(Permission::SCREEN_SHARE));
+}
+
+fn channel(id: &str, parent_id: &str, permission_sync: i32, overwrites: serde_json::Value, users: serde_json::Value) -> Channel {
+    serde_json::from_value(json!({
+        "id": id,
+        "name": format!("channel {}", id),
+        "parent_id": parent_id,
+        "permission_sync": permission_sync,
+        "permission_overwrites": overwrites,
+        "permission_users": users,
+    }))
+    .unwrap()
+}
+
+fn user_overwrite(user_id: &str, allow: Permission, deny: Permission) -> serde_json::Value {
+    json!({
+        "user": { "id": user_id, "username": "user" },
+        "allow": allow.bits(),
+        "deny": deny.bits(),
+    })
+}
+
+fn guild_roles() -> Vec<Role> {
+    vec![
+        role(0, Permission::VIEW_CHANNELS | Permission::SEND_MESSAGES),
+        role(1, Permission::ATTACH_FILES),
+        role(2, Permission::ADD_REACTIONS),
+    ]
+}
+
+#[test]
+fn guild_permissions_merge_member_roles() {
+    let roles = guild_roles();
+
+    assert_eq!(
+        permission::guild_permissions(&roles, &[]),
+        Permission::VIEW_CHANNELS | Permission::SEND_MESSAGES,
+    );
+    assert_eq!(
+        permission::guild_permissions(&roles, &[1, 2]),
+        Permission::VIEW_CHANNELS | Permission::SEND_MESSAGES | Permission::ATTACH_FILES | Permission::ADD_REACTIONS,
+    );
+}
+
+#[test]
+fn member_role_overwrites_apply_after_everyone() {
+    let roles = guild_roles();
+    let channel = channel("10", "", 0, json!([
+        { "role_id": 0, "allow": 0, "deny": Permission::SEND_MESSAGES.bits() },
+        { "role_id": 1, "allow": Permission::SEND_MESSAGES.bits(), "deny": Permission::ATTACH_FILES.bits() },
+        { "role_id": 2, "allow": 0, "deny": Permission::VIEW_CHANNELS.bits() },
+    ]), json!([]));
+
+    // 没有角色的成员只受 @全体成员 覆写影响
+    let everyone = permission::channel_permissions(&roles, "100", &[], &channel, None);
+    assert_eq!(everyone, Permission::VIEW_CHANNELS);
+
+    // 角色覆写合并后应用，允许会覆盖 @全体成员 的禁止
+    let member = permission::channel_permissions(&roles, "100", &[1], &channel, None);
+    assert_eq!(member, Permission::VIEW_CHANNELS | Permission::SEND_MESSAGES);
+
+    // 不属于成员的角色覆写不生效，同时拥有多个角色时禁止与允许分别合并
+    let both = permission::channel_permissions(&roles, "100", &[1, 2], &channel, None);
+    assert_eq!(both, Permission::SEND_MESSAGES | Permission::ADD_REACTIONS);
+}
+
+#[test]
+fn user_overwrite_applies_last() {
+    let roles = guild_roles();
+    let channel = channel("10", "", 0, json!([
+        { "role_id": 1, "allow": Permission::MANAGE_MESSAGES.bits(), "deny": 0 },
+    ]), json!([
+        user_overwrite("100", Permission::CONNECT, Permission::MANAGE_MESSAGES),
+        user_overwrite("200", Permission::SPEAK, Permission::empty()),
+    ]));
+
+    let permissions = permission::channel_permissions(&roles, "100", &[1], &channel, None);
+
+    assert!(permissions.contains(Permission::CONNECT));
+    assert!(!permissions.contains(Permission::MANAGE_MESSAGES));
+    assert!(!permissions.contains(Permission::SPEAK));
+}
+
+#[test]
+fn synced_channel_uses_parent_overwrites() {
+    let roles = guild_roles();
+    let parent = channel("1", "", 0, json!([
+        { "role_id": 0, "allow": 0, "deny": Permission::SEND_MESSAGES.bits() },
+    ]), json!([]));
+    let synced = channel("10", "1", 1, json!([
+        { "role_id": 0, "allow": Permission::MENTION_EVERYONE.bits(), "deny": 0 },
+    ]), json!([]));
+    let unsynced = channel("11", "1", 0, json!([
+        { "role_id": 0, "allow": Permission::MENTION_EVERYONE.bits(), "deny": 0 },
+    ]), json!([]));
+
+    let permissions = permission::channel_permissions(&roles, "100", &[], &synced, Some(&parent));
+    assert_eq!(permissions, Permission::VIEW_CHANNELS);
+
+    // 未同步权限或分组不匹配时使用频道自身的覆写
+    let own = Permission::VIEW_CHANNELS | Permission::SEND_MESSAGES | Permission::MENTION_EVERYONE;
+    assert_eq!(permission::channel_permissions(&roles, "100", &[], &unsynced, Some(&parent)), own);
+    let other_parent = channel("2", "", 0, json!([]), json!([]));
+    assert_eq!(permission::channel_permissions(&roles, "100", &[], &synced, Some(&other_parent)), own);
+    assert_eq!(permission::channel_permissions(&roles, "100", &[], &synced, None), own);
+}
+
+#[test]
+fn admin_ignores_overwrites() {
+    let roles = [role(0, Permission::VIEW_CHANNELS), role(1, Permission::ADMIN)];
+    let channel = channel("10", "", 0, json!([
+        { "role_id": 0, "allow": 0, "deny": Permission::KNOWN.bits() },
+        { "role_id": 1, "allow": 0, "deny": Permission::KNOWN.bits() },
+    ]), json!([
+        user_overwrite("100", Permission::empty(), Permission::KNOWN),
+    ]));
+
+    let permissions = permission::channel_permissions(&roles, "100", &[1], &channel, None);
+
+    assert_eq!(permissions, Permission::KNOWN);
+    assert!(permissions.has(Permission::MANAGE_GUILD | Permission::SPEAK));
+}
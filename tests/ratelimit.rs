@@ -0,0 +1,106 @@
+//! 速率限制响应头的解析和限速桶的等待
+use kook_sdk::{RateLimitInfo, RateLimiter};
+use reqwest::header::{HeaderMap, HeaderValue};
+use std::time::{Duration, Instant};
+
+fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    for (name, value) in pairs {
+        headers.insert(*name, HeaderValue::from_str(value).unwrap());
+    }
+    headers
+}
+
+#[test]
+fn parse_rate_limit_headers() {
+    let info = RateLimitInfo::from_headers(&headers(&[
+        ("X-Rate-Limit-Limit", "120"),
+        ("X-Rate-Limit-Remaining", "119"),
+        ("X-Rate-Limit-Reset", "1.5"),
+        ("X-Rate-Limit-Bucket", "message/create"),
+    ]));
+
+    assert_eq!(info.limit, Some(120));
+    assert_eq!(info.remaining, Some(119));
+    assert_eq!(info.reset_after, Some(Duration::from_millis(1500)));
+    assert_eq!(info.bucket.as_deref(), Some("message/create"));
+    assert!(!info.global);
+    assert_eq!(info.retry_after(), Duration::from_millis(1500));
+}
+
+#[test]
+fn missing_headers_use_default_retry_after() {
+    let info = RateLimitInfo::from_headers(&headers(&[
+        ("X-Rate-Limit-Reset", "-1"),
+        ("X-Rate-Limit-Global", ""),
+    ]));
+
+    assert_eq!(info.limit, None);
+    assert_eq!(info.reset_after, None);
+    assert!(info.global);
+    assert_eq!(info.retry_after(), Duration::from_secs(1));
+}
+
+#[test]
+fn out_of_range_reset_uses_default_retry_after() {
+    for reset in ["1e300", "NaN", "inf"] {
+        let info = RateLimitInfo::from_headers(&headers(&[("X-Rate-Limit-Reset", reset)]));
+
+        assert_eq!(info.reset_after, None);
+        assert_eq!(info.retry_after(), Duration::from_secs(1));
+    }
+}
+
+#[tokio::test]
+async fn acquire_waits_for_exhausted_bucket() {
+    let limiter = RateLimiter::new();
+    let info = RateLimitInfo::from_headers(&headers(&[
+        ("X-Rate-Limit-Limit", "2"),
+        ("X-Rate-Limit-Remaining", "1"),
+        ("X-Rate-Limit-Reset", "0.2"),
+        ("X-Rate-Limit-Bucket", "guild/list"),
+    ]));
+    limiter.update("/v3/guild/list", &info);
+
+    // 剩余的一次额度不需要等待
+    let start = Instant::now();
+    limiter.acquire("v3/guild/list").await;
+    assert!(start.elapsed() < Duration::from_millis(100));
+    assert_eq!(limiter.bucket("guild/list").unwrap().remaining, 0);
+
+    // 额度耗尽后等待桶重置
+    limiter.acquire("/v3/guild/list").await;
+    assert!(start.elapsed() >= Duration::from_millis(150));
+    assert_eq!(limiter.bucket("guild/list").unwrap().remaining, 1);
+}
+
+#[tokio::test]
+async fn mark_exhausted_without_bucket_uses_path() {
+    let limiter = RateLimiter::new();
+    let info = RateLimitInfo::from_headers(&headers(&[("X-Rate-Limit-Reset", "0.2")]));
+    limiter.mark_exhausted("/v3/user/me", &info);
+
+    assert_eq!(limiter.bucket("v3/user/me").unwrap().remaining, 0);
+
+    // 其他路径不受影响
+    let start = Instant::now();
+    limiter.acquire("/v3/guild/list").await;
+    assert!(start.elapsed() < Duration::from_millis(100));
+
+    limiter.acquire("/v3/user/me").await;
+    assert!(start.elapsed() >= Duration::from_millis(150));
+}
+
+#[tokio::test]
+async fn global_limit_delays_every_route() {
+    let limiter = RateLimiter::new();
+    let info = RateLimitInfo::from_headers(&headers(&[
+        ("X-Rate-Limit-Reset", "0.2"),
+        ("X-Rate-Limit-Global", "1"),
+    ]));
+    limiter.update("/v3/message/create", &info);
+
+    let start = Instant::now();
+    limiter.acquire("/v3/channel/list").await;
+    assert!(start.elapsed() >= Duration::from_millis(150));
+}
@@ -0,0 +1,71 @@
+//! 重试策略的判断和退避时间
+use kook_sdk::*;
+use reqwest::Method;
+use std::time::Duration;
+
+fn api_error(kind: ApiErrorKind) -> KookError {
+    KookError::Api(ApiError {
+        kind,
+        code: None,
+        message: String::new(),
+        status: None,
+        path: None,
+        body: None,
+    })
+}
+
+#[test]
+fn default_policy_retries_safe_requests() {
+    let policy = RetryPolicy::default();
+
+    assert!(policy.is_retryable_request(&Method::GET));
+    assert!(policy.is_retryable_request(&Method::HEAD));
+    assert!(!policy.is_retryable_request(&Method::POST));
+    assert!(!policy.is_retryable_request(&Method::DELETE));
+}
+
+#[test]
+fn retryable_errors() {
+    let policy = RetryPolicy::default();
+
+    assert!(policy.is_retryable_error(&api_error(ApiErrorKind::RateLimited)));
+    assert!(policy.is_retryable_error(&api_error(ApiErrorKind::ServerError)));
+    assert!(policy.is_retryable_error(&KookError::Network("timeout".to_string())));
+    assert!(!policy.is_retryable_error(&api_error(ApiErrorKind::Forbidden)));
+    assert!(!policy.is_retryable_error(&KookError::Json("bad".to_string())));
+
+    let policy = RetryPolicy {
+        retry_on_server_error: false,
+        ..RetryPolicy::default()
+    };
+    assert!(!policy.is_retryable_error(&api_error(ApiErrorKind::ServerError)));
+}
+
+#[test]
+fn backoff_grows_until_max() {
+    let policy = RetryPolicy {
+        initial_backoff: Duration::from_millis(100),
+        max_backoff: Duration::from_millis(350),
+        multiplier: 2.0,
+        jitter: false,
+        ..RetryPolicy::default()
+    };
+
+    assert_eq!(policy.backoff(1), Duration::from_millis(100));
+    assert_eq!(policy.backoff(2), Duration::from_millis(200));
+    assert_eq!(policy.backoff(3), Duration::from_millis(350));
+}
+
+#[test]
+fn jitter_stays_within_half_of_delay() {
+    let policy = RetryPolicy {
+        initial_backoff: Duration::from_millis(100),
+        jitter: true,
+        ..RetryPolicy::default()
+    };
+
+    for _ in 0..100 {
+        let delay = policy.backoff(1);
+        assert!(delay >= Duration::from_millis(50) && delay <= Duration::from_millis(100));
+    }
+}
@@ -0,0 +1,60 @@
+//! 网关连接通过 HTTP 代理建立隧道
+use kook_sdk::websocket::proxy_tunnel;
+use kook_sdk::KookError;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// 读取代理收到的 CONNECT 请求头
+async fn read_request(stream: &mut TcpStream) -> String {
+    let mut request = Vec::new();
+    let mut byte = [0u8; 1];
+    while !request.ends_with(b"\r\n\r\n") {
+        assert_eq!(stream.read(&mut byte).await.unwrap(), 1);
+        request.push(byte[0]);
+    }
+    String::from_utf8(request).unwrap()
+}
+
+/// 启动只处理一次 CONNECT 请求的本地代理，返回带账号密码的代理主机，任务结束时得到收到的请求头和隧道数据
+async fn start_proxy(response: &'static str) -> (String, tokio::task::JoinHandle<(String, Vec<u8>)>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let handle = tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let request = read_request(&mut stream).await;
+        stream.write_all(response.as_bytes()).await.unwrap();
+        // 隧道建立后转发的数据
+        let mut tunneled = Vec::new();
+        stream.read_to_end(&mut tunneled).await.unwrap();
+        (request, tunneled)
+    });
+    (format!("user:p%40ss@{}", addr), handle)
+}
+
+#[tokio::test]
+async fn http_proxy_sends_connect_with_credentials() {
+    let (authority, proxy) = start_proxy("HTTP/1.1 200 Connection established\r\n\r\n").await;
+
+    let mut stream = proxy_tunnel(&format!("http://{}", authority), "ws.kookapp.cn", 443).await.unwrap();
+    stream.write_all(b"hello").await.unwrap();
+    drop(stream);
+
+    let (request, tunneled) = proxy.await.unwrap();
+    let mut lines = request.lines();
+    assert_eq!(lines.next(), Some("CONNECT ws.kookapp.cn:443 HTTP/1.1"));
+    let headers: Vec<&str> = lines.filter(|line| !line.is_empty()).collect();
+    assert!(headers.contains(&"Host: ws.kookapp.cn:443"));
+    // base64("user:p@ss")
+    assert!(headers.contains(&"Proxy-Authorization: Basic dXNlcjpwQHNz"));
+    assert_eq!(tunneled, b"hello");
+}
+
+#[tokio::test]
+async fn http_proxy_rejection_is_an_error() {
+    let (authority, proxy) = start_proxy("HTTP/1.1 407 Proxy Authentication Required\r\n\r\n").await;
+
+    let err = proxy_tunnel(&format!("http://{}", authority), "ws.kookapp.cn", 443).await.unwrap_err();
+
+    assert!(matches!(err, KookError::WebSocket(message) if message.contains("407")));
+    proxy.await.unwrap();
+}